@@ -0,0 +1,132 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// Build information for the site footer (see `src/footer.rs`), passed to the crate as environment
+// variables read with `option_env!`. Anything that can not be found is left unset.
+//
+// Versions are the locked ones from `Cargo.lock`. The lock file is not checked in, so when a build
+// has none the version required in `Cargo.toml` is used instead.
+
+// Crates whose locked version is shown, with the variable it is passed in.
+const CRATES: &[(&str, &str)] = &[
+    ("seed", "SEED_VERSION"),
+    ("seed_hooks", "SEED_HOOKS_VERSION"),
+    ("seed_style_preview", "SEED_STYLE_VERSION"),
+];
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest_dir = Path::new(&manifest_dir);
+
+    // cargo reruns the build script on every build while a watched file is missing
+    let lock_path = manifest_dir.join("Cargo.lock");
+    let manifest_path = manifest_dir.join("Cargo.toml");
+    for path in [&lock_path, &manifest_path].iter().filter(|path| path.exists()) {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+    let lock = fs::read_to_string(&lock_path).unwrap_or_default();
+    let manifest = fs::read_to_string(&manifest_path).unwrap_or_default();
+    for (name, variable) in CRATES {
+        if let Some(version) =
+            locked_version(&lock, name).or_else(|| required_version(&manifest, name))
+        {
+            println!("cargo:rustc-env={}={}", variable, version);
+        }
+    }
+
+    // the date of the last commit, which changes whenever the branch HEAD points to moves
+    for path in git_head_files(&manifest_dir.join(".git")) {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+    let date = Command::new("git")
+        .args(&["log", "-1", "--format=%cd", "--date=short"])
+        .current_dir(manifest_dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+    if let Some(date) = date.as_ref().map(|date| date.trim()).filter(|date| !date.is_empty()) {
+        println!("cargo:rustc-env=LAST_UPDATED={}", date);
+    }
+}
+
+// The version of the first `[[package]]` called `name` in a `Cargo.lock`, where the version line
+// follows the name line.
+fn locked_version(lock: &str, name: &str) -> Option<String> {
+    let name_line = format!("name = \"{}\"", name);
+    let mut lines = lock.lines().map(str::trim);
+    lines.find(|line| *line == name_line)?;
+    let version = lines.next()?;
+    if !version.starts_with("version = ") {
+        return None;
+    }
+    quoted(&version["version = ".len()..]).map(str::to_string)
+}
+
+// The version required of the dependency on the package `name` in a `Cargo.toml`, written either as
+// `name = "1.0"` or as `key = { package = "name", version = "1.0" }`.
+fn required_version(manifest: &str, name: &str) -> Option<String> {
+    manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[dependencies]")
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .find_map(|line| {
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next()?.trim();
+            let value = parts.next()?.trim();
+            if value.starts_with('{') {
+                let package = inline_field(value, "package").unwrap_or(key);
+                if package == name {
+                    return inline_field(value, "version").map(str::to_string);
+                }
+                None
+            } else if key == name {
+                quoted(value).map(str::to_string)
+            } else {
+                None
+            }
+        })
+}
+
+// The string `field = "..."` of an inline table.
+fn inline_field<'a>(table: &'a str, field: &str) -> Option<&'a str> {
+    table
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .split(',')
+        .find_map(|entry| {
+            let mut parts = entry.splitn(2, '=');
+            if parts.next()?.trim() != field {
+                return None;
+            }
+            quoted(parts.next()?.trim())
+        })
+}
+
+// The contents of a string written in double quotes.
+fn quoted(value: &str) -> Option<&str> {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        Some(&value[1..value.len() - 1])
+    } else {
+        None
+    }
+}
+
+// The files that change when a commit is made: `HEAD`, the branch it points to and, for branches
+// that `git gc` has packed, `packed-refs`. Only the files that exist are given.
+fn git_head_files(git_dir: &Path) -> Vec<PathBuf> {
+    let head = git_dir.join("HEAD");
+    let mut files = vec![git_dir.join("packed-refs")];
+    if let Ok(contents) = fs::read_to_string(&head) {
+        let contents = contents.trim();
+        if contents.starts_with("ref: ") {
+            files.push(git_dir.join(&contents["ref: ".len()..]));
+        }
+    }
+    files.push(head);
+    files.into_iter().filter(|file| file.exists()).collect()
+}
@@ -0,0 +1,273 @@
+use crate::app_styling::theme::*;
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::px;
+use seed_style::*;
+use std::collections::HashMap;
+
+// Heading anchors
+// ---------------
+//
+// Every `h2` and `h3` of prose content gets an `id` slugged from its text and a "#" permalink
+// that shows when the heading is hovered, this means any section can be linked to.
+//
+// Like highlighting this works on the virtual dom of the rendered page. Only the headings of prose,
+// written with `prose!`, are anchored, the headings of components shown on a page are not sections.
+//
+// Slugs are lower case, each run of spaces and hyphens becomes one hyphen, punctuation is dropped
+// and repeated headings on a page are suffixed `-1`, `-2`...
+
+// Turns heading text into a slug, e.g. "Hooks API: use_state" -> "hooks-api-use_state".
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    let mut pending_hyphen = false;
+
+    for c in text.trim().chars() {
+        if c.is_alphanumeric() || c == '_' {
+            if pending_hyphen && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_hyphen = false;
+            slug.extend(c.to_lowercase());
+        } else if c.is_whitespace() || c == '-' {
+            pending_hyphen = true;
+        }
+    }
+    slug
+}
+
+// Hands out unique slugs for the headings of one page.
+#[derive(Default)]
+pub struct Slugger {
+    seen: HashMap<String, usize>,
+}
+
+impl Slugger {
+    pub fn new() -> Slugger {
+        Slugger::default()
+    }
+
+    pub fn slug(&mut self, text: &str) -> String {
+        let base = match slugify(text) {
+            slug if slug.is_empty() => "section".to_string(),
+            slug => slug,
+        };
+        self.unique(base)
+    }
+
+    // Reserves an id that is already in use, so that no generated slug collides with it.
+    pub fn reserve(&mut self, id: &str) {
+        self.seen.entry(id.to_string()).or_insert(0);
+    }
+
+    fn unique(&mut self, base: String) -> String {
+        let mut slug = base.clone();
+        while self.seen.contains_key(&slug) {
+            let count = self.seen.entry(base.clone()).or_insert(0);
+            *count += 1;
+            slug = format!("{}-{}", base, count);
+        }
+        self.seen.insert(slug.clone(), 0);
+        slug
+    }
+}
+
+// A heading that has been given an anchor, used to build a table of contents.
+pub struct Heading {
+    pub level: u8,
+    pub id: String,
+    pub text: String,
+}
+
+// Adds ids and permalinks to the `h2` and `h3` children of `.prose` elements in `node`.
+pub fn add_heading_anchors<Ms: 'static>(node: &mut Node<Ms>, slugger: &mut Slugger) {
+    if let Node::Element(el) = node {
+        let is_prose = has_class(el, "prose");
+        for child in el.children.iter_mut() {
+            match child {
+                Node::Element(heading) if is_prose && is_anchored_heading(heading) => {
+                    add_anchor(heading, slugger)
+                }
+                _ => add_heading_anchors(child, slugger),
+            }
+        }
+    }
+}
+
+fn is_anchored_heading<Ms>(el: &El<Ms>) -> bool {
+    el.tag == Tag::H2 || el.tag == Tag::H3
+}
+
+fn add_anchor<Ms: 'static>(heading: &mut El<Ms>, slugger: &mut Slugger) {
+    // Articles with a table of contents anchor their headings before the rest of the page.
+    if has_class(heading, "anchored") {
+        if let Some(AtValue::Some(id)) = heading.attrs.vals.get(&At::Id) {
+            slugger.reserve(id);
+        }
+        return;
+    }
+
+    // Headings with an id set by hand keep it, they are assumed to already be linked to.
+    let id = match heading.attrs.vals.get(&At::Id) {
+        Some(AtValue::Some(id)) => {
+            let id = id.clone();
+            slugger.reserve(&id);
+            id
+        }
+        _ => {
+            let id = slugger.slug(&text_content(heading));
+            heading.attrs.add(At::Id, &id);
+            id
+        }
+    };
+
+    heading.add_class("anchored");
+    heading.add_child(a![
+        class!["heading-anchor"],
+        attrs! {
+            At::Href => format!("#{}", id),
+            At::from("aria-label") => format!("Link to {}", text_content(heading)),
+        },
+        "#"
+    ]);
+}
+
+// The anchored headings in `node`, in document order.
+pub fn collect_headings<Ms>(node: &Node<Ms>) -> Vec<Heading> {
+    let mut headings = vec![];
+    push_headings(node, &mut headings);
+    headings
+}
+
+fn push_headings<Ms>(node: &Node<Ms>, headings: &mut Vec<Heading>) {
+    if let Node::Element(el) = node {
+        match el.attrs.vals.get(&At::Id) {
+            Some(AtValue::Some(id)) if has_class(el, "anchored") => headings.push(Heading {
+                level: if el.tag == Tag::H2 { 2 } else { 3 },
+                id: id.clone(),
+                text: text_content(el),
+            }),
+            _ => {
+                for child in el.children.iter() {
+                    push_headings(child, headings);
+                }
+            }
+        }
+    }
+}
+
+fn has_class<Ms>(el: &El<Ms>, class: &str) -> bool {
+    match el.attrs.vals.get(&At::Class) {
+        Some(AtValue::Some(classes)) => classes.split_whitespace().any(|c| c == class),
+        _ => false,
+    }
+}
+
+// Text of an element including nested elements, headings often contain `code` spans.
+pub fn text_content<Ms>(el: &El<Ms>) -> String {
+    el.children
+        .iter()
+        .map(|child| match child {
+            Node::Text(text) => text.text.to_string(),
+            Node::Element(el) if !has_class(el, "heading-anchor") => text_content(el),
+            _ => String::new(),
+        })
+        .collect()
+}
+
+#[reaction]
+pub fn heading_anchor_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            // keep headings clear of the fixed header when jumped to
+            .style(
+                ".anchored",
+                s().raw("scroll-margin-top: calc(var(--header-offset) + 16px);"),
+            )
+            .style(
+                ".heading-anchor",
+                s().ml(px(8))
+                    .color(Color::MutedText)
+                    .text_decoration_none()
+                    .opacity("0")
+                    .transition("opacity 0.2s ease-out 0s"),
+            )
+            .style(
+                ".anchored:hover .heading-anchor, .heading-anchor:focus",
+                s().opacity("1"),
+            )
+            .style(".heading-anchor", s().hover().color(Color::Primary))
+            .style(".heading-anchor", s().media("@media print").display_none())
+            .activate_styles()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_lowercases_and_hyphenates() {
+        assert_eq!(slugify("Getting Started"), "getting-started");
+    }
+
+    #[test]
+    fn slugify_drops_punctuation() {
+        assert_eq!(slugify("Hooks API: use_state()"), "hooks-api-use_state");
+        assert_eq!(slugify("What's `Seed` for?"), "whats-seed-for");
+    }
+
+    #[test]
+    fn slugify_collapses_whitespace_and_hyphens() {
+        assert_eq!(slugify("  Layout  -  Areas  "), "layout-areas");
+        assert_eq!(slugify("--leading and trailing--"), "leading-and-trailing");
+    }
+
+    #[test]
+    fn slugify_keeps_unicode_letters() {
+        assert_eq!(slugify("Über Größe"), "über-größe");
+    }
+
+    #[test]
+    fn slugs_are_deterministic() {
+        let first: Vec<_> = {
+            let mut slugger = Slugger::new();
+            vec![slugger.slug("Setup"), slugger.slug("Usage")]
+        };
+        let second: Vec<_> = {
+            let mut slugger = Slugger::new();
+            vec![slugger.slug("Setup"), slugger.slug("Usage")]
+        };
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn duplicate_headings_are_numbered() {
+        let mut slugger = Slugger::new();
+        assert_eq!(slugger.slug("Example"), "example");
+        assert_eq!(slugger.slug("Example"), "example-1");
+        assert_eq!(slugger.slug("example"), "example-2");
+    }
+
+    #[test]
+    fn numbered_slugs_do_not_collide_with_real_headings() {
+        let mut slugger = Slugger::new();
+        assert_eq!(slugger.slug("Example 1"), "example-1");
+        assert_eq!(slugger.slug("Example"), "example");
+        assert_eq!(slugger.slug("Example"), "example-2");
+    }
+
+    #[test]
+    fn reserved_ids_are_skipped() {
+        let mut slugger = Slugger::new();
+        slugger.reserve("usage");
+        assert_eq!(slugger.slug("Usage"), "usage-1");
+    }
+
+    #[test]
+    fn empty_headings_get_a_fallback_slug() {
+        let mut slugger = Slugger::new();
+        assert_eq!(slugger.slug("!!!"), "section");
+        assert_eq!(slugger.slug(""), "section-1");
+    }
+}
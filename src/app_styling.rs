@@ -1,2 +1,4 @@
 pub mod global_styles;
+pub mod markdown_styles;
 pub mod theme;
+pub mod typography;
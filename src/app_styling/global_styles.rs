@@ -1,4 +1,5 @@
 use super::theme::*;
+use crate::highlight::TOKEN_COLORS;
 use seed_style::*;
 use seed_hooks::*;
 
@@ -14,17 +15,26 @@ pub fn init_styles() {
         .style("*, *:before, *:after", s().box_sizing("inherit"))
         // make sure we never ever get horizontal scroll bars
         .style("body", s().max_width(vw(100)))
-        .style("ul,ol", s().list_style_position_inside().pl(px(8)))
+        .style("ul,ol", s().list_style_type_none().pl(px(0)))
+        // the page behind the open nav drawer does not scroll
+        .style("body[data-drawer-open]", s().overflow_hidden())
+        // space taken by the header at the top of the window, see `header::on_window_scroll`
+        .style("body", s().raw("--header-offset: 64px;"))
+        .style("body[data-header-hidden]", s().raw("--header-offset: 0px;"))
         .activate_init_styles()
 }
 
+// Element styles for prose are scoped to `prose` elements, which wrap the output of `prose!`.
+// Headings, lists and paragraphs outside of them, such as in the header, nav, palette and the
+// components shown alongside the documentation, are left unstyled.
 #[reaction]
 pub fn themed_global_styles() -> () {
     app_themes().on_update( ||
     GlobalStyle::new()
         .style(
-            "p",
-            s().line_height(CssLineHeight::Number(1.6))
+            ".prose p",
+            s().font_size(FontSize::Body)
+                .line_height(LineHeight::Body)
                 .letter_spacing(rem(-0.00278))
                 .mt(3),
         )
@@ -35,11 +45,19 @@ pub fn themed_global_styles() -> () {
         .style("a", s().color(Color::MainText))
         .style("a", s().visited().color(Color::MainText))
         .style("a", s().hover().color(Color::Primary))
-        .style("ul", s().list_style_type_disc().mt(px(8)))
-        .style("li", s().list_style_type_disc().mt(px(4)))
-        .style("p img", s().mx_auto())
+        // the link to the current page, e.g. in the nav drawer, stays highlighted once visited
+        .style("a[aria-current='page']", s().color(Color::Primary))
+        .style("a[aria-current='page']", s().visited().color(Color::Primary))
         .style(
-            "p code",
+            ".prose ul, .prose ol",
+            s().list_style_position_inside().pl(px(8)).mt(px(8)),
+        )
+        .style(".prose ul", s().list_style_type_disc())
+        .style(".prose ol", s().list_style_type("decimal"))
+        .style(".prose li", s().mt(px(4)))
+        .style(".prose p img", s().mx_auto())
+        .style(
+            ".prose p code",
             s().color(Color::MainText)
                 .font_size(0)
                 .m(0)
@@ -48,16 +66,17 @@ pub fn themed_global_styles() -> () {
                 .bg_color(Color::MutedSecondary),
         )
         .style(
-            "h1",
+            ".prose h1",
             s().font_weight_v900()
                 .display_block()
                 .py(3)
-                .mt(px(64 + 8))
+                .mt(em(2))
                 .mb(5)
-                .font_size(px(36)),
+                .font_size(FontSize::H1)
+                .line_height(LineHeight::H1),
         )
         .style(
-            "h2",
+            ".prose h2",
             s().font_weight_v900()
                 .b_style_solid()
                 .bb_width(1)
@@ -66,19 +85,21 @@ pub fn themed_global_styles() -> () {
                 .py(3)
                 .mt(3)
                 .mb(4)
-                .font_size(px(24)),
+                .font_size(FontSize::H2)
+                .line_height(LineHeight::H2),
         )
         .style(
-            "h3",
+            ".prose h3",
             s().font_style_italic()
                 .font_weight_v700()
                 .display_block()
                 .mt(1)
                 .mb(2)
-                .font_size(px(24)),
+                .font_size(FontSize::H3)
+                .line_height(LineHeight::H3),
         )
         .style(
-            "pre",
+            ".prose pre",
             s().color(Color::MainText)
                 .display_flex()
                 .flex_direction_row()
@@ -92,7 +113,7 @@ pub fn themed_global_styles() -> () {
                 .my(2),
         )
         .style(
-            "pre > code",
+            ".prose pre > code",
             s().display_inline_block()
                 .bg_color(Color::MutedSecondary)
                 .b_style_dotted()
@@ -111,3 +132,82 @@ pub fn themed_global_styles() -> () {
     
         
 }
+
+// Print styles for documentation pages.
+//
+// The header and nav drawer hide themselves when printing and the centred article expands to the
+// full page width (see `header.rs`, `nav.rs` and `compositions.rs`). These rules take care of the content:
+// long code lines wrap instead of scrolling, link urls are printed after the link text and colours,
+// including those of highlighted code and borders, are the light theme's whichever theme is active.
+#[reaction]
+pub fn print_styles() -> () {
+    app_themes().on_update(|| {
+        let light = light_colors();
+        let color = |alias: Color| {
+            light
+                .iter()
+                .find(|(light_alias, _)| *light_alias == alias)
+                .map(|(_, color)| color.clone())
+                .expect("the light theme sets every colour")
+        };
+
+        let styles = GlobalStyle::new()
+            .style(
+                "body, .prose, .markdown-body",
+                s().media("@media print")
+                    .bg_color(color(Color::Background))
+                    .color(color(Color::MainText)),
+            )
+            .style(
+                "a, a:visited",
+                s().media("@media print").color(color(Color::MainText)),
+            )
+            .style(
+                ".prose a[href]:not([href^='#'])::after, .markdown-body a[href]:not([href^='#'])::after",
+                s().media("@media print")
+                    .content(r#"" (" attr(href) ")""#)
+                    .font_size(FontSize::Caption)
+                    .word_break("break-all"),
+            )
+            .style(
+                "pre, pre > code",
+                s().media("@media print")
+                    .overflow_x("visible")
+                    .white_space("pre-wrap")
+                    .word_break("break-word"),
+            )
+            .style(
+                "code, .markdown-body pre, .markdown-body hr, .markdown-body tr:nth-child(2n)",
+                s().media("@media print")
+                    .color(color(Color::MainText))
+                    .bg_color(color(Color::MutedSecondary)),
+            )
+            .style(
+                ".prose h2",
+                s().media("@media print").b_color(color(Color::Primary)),
+            )
+            .style(
+                ".prose pre > code",
+                s().media("@media print").b_color(color(Color::Secondary)),
+            )
+            .style(
+                ".markdown-body h1, .markdown-body h2, .markdown-body th, .markdown-body td, .markdown-body blockquote",
+                s().media("@media print").b_color(color(Color::MutedSecondary)),
+            )
+            .style(
+                "h1, h2, h3",
+                s().media("@media print").page_break_after("avoid"),
+            )
+            .style(
+                "pre, img",
+                s().media("@media print").page_break_inside("avoid"),
+            );
+
+        TOKEN_COLORS
+            .iter()
+            .fold(styles, |styles, (selector, alias)| {
+                styles.style(selector, s().media("@media print").color(color(alias.clone())))
+            })
+            .activate_styles()
+    });
+}
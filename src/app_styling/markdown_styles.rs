@@ -0,0 +1,145 @@
+use super::theme::*;
+use seed_hooks::*;
+use seed_style::*;
+
+// Markdown prose styles scoped to `.markdown-body`.
+//
+// These follow the layout of the github markdown stylesheet, however all colours come from
+// `Color` aliases and all sizes from the typography aliases, therefore the rendered markdown
+// follows the active theme (including dark mode) and nothing needs to be fetched from a CDN.
+#[reaction]
+pub fn markdown_body_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(
+                ".markdown-body",
+                s().color(Color::MainText)
+                    .bg_color(Color::Background)
+                    .font_size(FontSize::Body)
+                    .line_height(LineHeight::Body)
+                    .word_wrap("break-word"),
+            )
+            .style(".markdown-body > *:first-child", s().mt(px(0)))
+            .style(".markdown-body > *:last-child", s().mb(px(0)))
+            .style(
+                ".markdown-body p, .markdown-body blockquote, .markdown-body ul, .markdown-body ol, .markdown-body table, .markdown-body pre",
+                s().mt(px(0)).mb(px(16)),
+            )
+            .style(
+                ".markdown-body h1, .markdown-body h2, .markdown-body h3, .markdown-body h4, .markdown-body h5, .markdown-body h6",
+                s().font_weight_v700()
+                    .font_style("normal")
+                    .mt(px(24))
+                    .mb(px(16))
+                    .py(px(0)),
+            )
+            .style(
+                ".markdown-body h1, .markdown-body h2",
+                s().pb(px(5))
+                    .b_width(px(0))
+                    .bb_width(px(1))
+                    .b_style_solid()
+                    .b_color(Color::MutedSecondary),
+            )
+            .style(
+                ".markdown-body h1",
+                s().font_size(FontSize::H1).line_height(LineHeight::H1),
+            )
+            .style(
+                ".markdown-body h2",
+                s().font_size(FontSize::H2).line_height(LineHeight::H2),
+            )
+            .style(
+                ".markdown-body h3",
+                s().font_size(FontSize::H3).line_height(LineHeight::H3),
+            )
+            .style(".markdown-body h4", s().font_size(FontSize::Body))
+            .style(
+                ".markdown-body h5, .markdown-body h6",
+                s().font_size(FontSize::Caption),
+            )
+            .style(
+                ".markdown-body a",
+                s().color(Color::Primary).text_decoration_none(),
+            )
+            .style(
+                ".markdown-body a",
+                s().hover().text_decoration("underline"),
+            )
+            .style(
+                ".markdown-body ul, .markdown-body ol",
+                s().pl(em(2)).list_style_position("outside"),
+            )
+            .style(".markdown-body ul", s().list_style_type_disc())
+            .style(".markdown-body ol", s().list_style_type("decimal"))
+            .style(".markdown-body li + li", s().mt(em(0.25)))
+            .style(
+                ".markdown-body blockquote",
+                s().mx(px(0))
+                    .px(em(1))
+                    .color(Color::MutedText)
+                    .border_left_width(px(4))
+                    .border_left_style("solid")
+                    .border_left_color(Color::MutedSecondary),
+            )
+            .style(
+                ".markdown-body hr",
+                s().h(px(4))
+                    .p(px(0))
+                    .my(px(24))
+                    .b_width(px(0))
+                    .bg_color(Color::MutedSecondary),
+            )
+            .style(
+                ".markdown-body code",
+                s().py(em(0.2))
+                    .px(em(0.4))
+                    .m(px(0))
+                    .font_size(pc(85))
+                    .radius(px(3))
+                    .bg_color(Color::MutedSecondary),
+            )
+            .style(
+                ".markdown-body pre",
+                s().p(px(16))
+                    .overflow_auto()
+                    .font_size(pc(85))
+                    .line_height(CssLineHeight::Number(1.45))
+                    .radius(px(6))
+                    .bg_color(Color::MutedSecondary),
+            )
+            .style(
+                ".markdown-body pre > code",
+                s().p(px(0))
+                    .font_size(pc(100))
+                    .white_space("pre")
+                    .bg_color("transparent")
+                    .b_width(px(0)),
+            )
+            .style(
+                ".markdown-body table",
+                s().display_block()
+                    .w(pc(100))
+                    .overflow_auto()
+                    .border_collapse("collapse"),
+            )
+            .style(
+                ".markdown-body th, .markdown-body td",
+                s().py(px(6))
+                    .px(px(13))
+                    .b_width(px(1))
+                    .b_style_solid()
+                    .b_color(Color::MutedSecondary),
+            )
+            .style(".markdown-body th", s().font_weight_v700())
+            .style(
+                ".markdown-body tr:nth-child(2n)",
+                s().bg_color(Color::MutedSecondary),
+            )
+            .style(
+                ".markdown-body img",
+                s().max_w(pc(100)).box_sizing_content_box(),
+            )
+            .activate_styles()
+    });
+}
@@ -1,3 +1,4 @@
+use super::typography::*;
 use seed_style::px;
 use seed_style::*;
 // Theme Definition
@@ -26,6 +27,7 @@ use seed_style::*;
 pub enum Color {
     Background,
     MainText,
+    MutedText,
     Primary,
     MutedPrimary,
     DarkPrimary,
@@ -33,6 +35,13 @@ pub enum Color {
     Secondary,
     DarkSecondary,
     Highlight,
+    // syntax highlighting
+    CodeKeyword,
+    CodeType,
+    CodeFunction,
+    CodeString,
+    CodeNumber,
+    CodeComment,
 }
 impl ColorTheme for Color {} // Allows you to use a `Color` variant as a CssColor alias in the theme.
 
@@ -47,11 +56,58 @@ pub enum Breakpoint {
 }
 impl BreakpointTheme for Breakpoint {} // Enable `Breakpoint` as a Breakpoint alias.
 
+// The window widths of each breakpoint, shared by both themes.
+// Breakpoints are upper bound exclusive lower bound inclusive.
+pub fn breakpoint_range(breakpoint: &Breakpoint) -> (u32, Option<u32>) {
+    match breakpoint {
+        Breakpoint::ExtraSmall => (0, Some(600)),
+        Breakpoint::Small => (600, Some(960)),
+        Breakpoint::Medium => (960, Some(1280)),
+        Breakpoint::Large => (1280, Some(1920)),
+        Breakpoint::ExtraLarge => (1920, None),
+    }
+}
+
+// The breakpoint a window of `width` pixels is in.
+pub fn breakpoint_for_width(width: u32) -> Breakpoint {
+    use Breakpoint::*;
+    [ExtraSmall, Small, Medium, Large, ExtraLarge]
+        .iter()
+        .find(|breakpoint| {
+            let (from, to) = breakpoint_range(breakpoint);
+            width >= from && to.map_or(true, |to| width < to)
+        })
+        .cloned()
+        .unwrap_or(ExtraLarge)
+}
+
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
 pub enum Shadow {
     RightEdge,
 }
 impl ShadowTheme for Shadow {} // Enable `Breakpoint` as a Breakpoint alias.
+
+// Typographic aliases, these are filled in from a modular scale (see `typography.rs`)
+// rather than being set individually.
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub enum FontSize {
+    H1,
+    H2,
+    H3,
+    Body,
+    Caption,
+}
+impl FontSizeTheme for FontSize {}
+
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub enum LineHeight {
+    H1,
+    H2,
+    H3,
+    Body,
+    Caption,
+}
+impl LineHeightTheme for LineHeight {}
                                // The good thing about declaring the keys ahead of time is that it is easy for
                                // A user of the app / component to know what can be set to configure the style of the app
 
@@ -89,6 +145,7 @@ pub fn dark_theme() -> Theme {
     let theme = Theme::new("dark_theme")
         .set_color(Color::Background, CssColor::Hex(0x333333))
         .set_color(Color::MainText, CssColor::Hex(0xDDDDDD))
+        .set_color(Color::MutedText, CssColor::Hex(0x999999))
         .set_color(Color::Primary, CssColor::Hsl(300.0, 70.0, 45.0))
         .set_color(Color::DarkPrimary, hsl(300, 70, 30))
         .set_color(Color::MutedPrimary, CssColor::Hsl(300.0, 70.0, 90.0))
@@ -96,11 +153,17 @@ pub fn dark_theme() -> Theme {
         .set_color(Color::MutedSecondary, hsl(200, 15, 30)) // or use the hsl shortcut
         .set_color(Color::Highlight, hsl(10, 70, 85))
         .set_color(Color::DarkSecondary, hsl(300, 60, 20))
-        .set_breakpoint(ExtraSmall, (0, Some(600))) // Breakpoints are upper bound exclusive lower bound inclusive.
-        .set_breakpoint(Small, (600, Some(960)))
-        .set_breakpoint(Medium, (960, Some(1280)))
-        .set_breakpoint(Large, (1280, Some(1920)))
-        .set_breakpoint(ExtraLarge, (1920, None))
+        .set_color(Color::CodeKeyword, hsl(300, 60, 75))
+        .set_color(Color::CodeType, hsl(190, 60, 65))
+        .set_color(Color::CodeFunction, hsl(220, 80, 75))
+        .set_color(Color::CodeString, hsl(100, 50, 65))
+        .set_color(Color::CodeNumber, hsl(30, 80, 65))
+        .set_color(Color::CodeComment, CssColor::Hex(0x8B949E))
+        .set_breakpoint(ExtraSmall, breakpoint_range(&ExtraSmall)) // see `breakpoint_range`
+        .set_breakpoint(Small, breakpoint_range(&Small))
+        .set_breakpoint(Medium, breakpoint_range(&Medium))
+        .set_breakpoint(Large, breakpoint_range(&Large))
+        .set_breakpoint(ExtraLarge, breakpoint_range(&ExtraLarge))
         .set_shadow(Shadow::RightEdge, "8px 0px 6px -8px #222222");
     // other aliases you can set include :
     // color, space, size, font_size, font, border, border_width, border_style, border_radius, transition
@@ -115,28 +178,47 @@ pub fn dark_theme() -> Theme {
         .font_size_scale(&[px(14), px(18), px(20), px(36)])
         .breakpoint_scale([600, 960, 1280, 1920]); // standard-material-ui breakpoints
 
-    theme
+    // typography, 16px body text on a perfect fourth scale
+    set_modular_scale(theme, &ModularScale::new(16., 1.333))
+}
+
+// The colours of the light theme. Printed pages use them whichever theme is active, see
+// `print_styles`.
+pub fn light_colors() -> Vec<(Color, CssColor)> {
+    vec![
+        (Color::Background, CssColor::Hex(0xFFFFFF)),
+        (Color::MainText, CssColor::Hex(0x000000)),
+        (Color::MutedText, CssColor::Hex(0x6A737D)),
+        (Color::Primary, CssColor::Hsl(100.0, 70.0, 40.0)),
+        (Color::DarkPrimary, CssColor::Hsl(100.0, 70.0, 30.0)),
+        (Color::MutedPrimary, CssColor::Hsl(100.0, 70.0, 90.0)),
+        (Color::Secondary, CssColor::Hex(0xF6AD55)),
+        (Color::MutedSecondary, CssColor::Hex(0xFEEBC8)),
+        (Color::Highlight, CssColor::Hsl(310.0, 70.0, 85.0)),
+        (Color::DarkSecondary, CssColor::Hsl(300.0, 60.0, 20.0)),
+        (Color::CodeKeyword, CssColor::Hsl(300.0, 60.0, 35.0)),
+        (Color::CodeType, CssColor::Hsl(200.0, 70.0, 30.0)),
+        (Color::CodeFunction, CssColor::Hsl(220.0, 70.0, 45.0)),
+        (Color::CodeString, CssColor::Hsl(100.0, 60.0, 28.0)),
+        (Color::CodeNumber, CssColor::Hsl(25.0, 80.0, 38.0)),
+        (Color::CodeComment, CssColor::Hex(0x6A737D)),
+    ]
 }
 
 pub fn my_theme() -> Theme {
     use Breakpoint::*;
 
     // I generally set the named aliases separately from the theme scales:
-    let theme = Theme::new("light_theme")
-        .set_color(Color::Background, CssColor::Hex(0xFFFFFF))
-        .set_color(Color::MainText, CssColor::Hex(0x000000))
-        .set_color(Color::Primary, CssColor::Hsl(100.0, 70.0, 40.0))
-        .set_color(Color::DarkPrimary, hsl(100, 70, 30))
-        .set_color(Color::MutedPrimary, CssColor::Hsl(100.0, 70.0, 90.0))
-        .set_color(Color::Secondary, CssColor::Hex(0xF6AD55)) // or use the hsl shortcut
-        .set_color(Color::MutedSecondary, CssColor::Hex(0xFEEBC8)) // or use the hsl shortcut
-        .set_color(Color::Highlight, hsl(310, 70, 85))
-        .set_color(Color::DarkSecondary, hsl(300, 60, 20))
-        .set_breakpoint(ExtraSmall, (0, Some(600))) // Breakpoints are upper bound exclusive lower bound inclusive.
-        .set_breakpoint(Small, (600, Some(960)))
-        .set_breakpoint(Medium, (960, Some(1280)))
-        .set_breakpoint(Large, (1280, Some(1920)))
-        .set_breakpoint(ExtraLarge, (1920, None))
+    let theme = light_colors()
+        .into_iter()
+        .fold(Theme::new("light_theme"), |theme, (alias, color)| {
+            theme.set_color(alias, color)
+        })
+        .set_breakpoint(ExtraSmall, breakpoint_range(&ExtraSmall)) // see `breakpoint_range`
+        .set_breakpoint(Small, breakpoint_range(&Small))
+        .set_breakpoint(Medium, breakpoint_range(&Medium))
+        .set_breakpoint(Large, breakpoint_range(&Large))
+        .set_breakpoint(ExtraLarge, breakpoint_range(&ExtraLarge))
         .set_shadow(Shadow::RightEdge, "8px 0px 6px -8px #999999");
     // other aliases you can set include :
     // color, space, size, font_size, font, border, border_width, border_style, border_radius, transition
@@ -151,5 +233,16 @@ pub fn my_theme() -> Theme {
         .font_size_scale(&[px(14), px(18), px(20), px(36)])
         .breakpoint_scale([600, 960, 1280, 1920]); // standard-material-ui breakpoints
 
-    theme
+    // typography, 16px body text on a perfect fourth scale
+    set_modular_scale(theme, &ModularScale::new(16., 1.333))
+}
+
+// The named breakpoint the window is in.
+pub fn current_breakpoint() -> Breakpoint {
+    let width = seed::window()
+        .inner_width()
+        .ok()
+        .and_then(|width| width.as_f64())
+        .unwrap_or(0.);
+    breakpoint_for_width(width as u32)
 }
@@ -0,0 +1,107 @@
+use super::theme::*;
+use seed_style::px;
+use seed_style::*;
+
+// Typographic Modular Scale
+// -------------------------
+//
+// A modular scale is a sequence of font sizes where each step is the previous step
+// multiplied by a constant ratio, i.e. `base * ratio^step`.
+//
+// Rather than picking heading sizes by hand we pick a base size and a ratio and let the scale
+// fill the `FontSize` and `LineHeight` aliases of a theme. Global styles then refer to the aliases
+// so that typography can be adjusted centrally from the theme.
+//
+// https://www.modularscale.com/
+pub struct ModularScale {
+    pub base: f64,
+    pub ratio: f64,
+}
+
+// Line heights are rounded so that each line is a whole multiple of this many pixels.
+// Matches the 4px increments used in the space scale.
+const BASELINE_GRID: f64 = 4.;
+
+impl ModularScale {
+    pub fn new(base: f64, ratio: f64) -> ModularScale {
+        ModularScale { base, ratio }
+    }
+
+    // The size in whole pixels `step` places along the scale, step 0 being the base size.
+    pub fn step(&self, step: i32) -> f64 {
+        (self.base * self.ratio.powi(step)).round()
+    }
+
+    // A unitless line height for text at `step` with the preferred `leading`
+    // snapped to the baseline grid.
+    pub fn line_height(&self, step: i32, leading: f64) -> f64 {
+        let size = self.step(step);
+        (size * leading / BASELINE_GRID).ceil() * BASELINE_GRID / size
+    }
+}
+
+// Position of each typographic alias on the scale and its preferred leading.
+// Headings are set tighter than running text.
+fn scale_steps() -> Vec<(FontSize, LineHeight, i32, f64)> {
+    vec![
+        (FontSize::H1, LineHeight::H1, 3, 1.2),
+        (FontSize::H2, LineHeight::H2, 2, 1.25),
+        (FontSize::H3, LineHeight::H3, 1, 1.3),
+        (FontSize::Body, LineHeight::Body, 0, 1.6),
+        (FontSize::Caption, LineHeight::Caption, -1, 1.4),
+    ]
+}
+
+// Fills the `FontSize` and `LineHeight` aliases of a theme from a modular scale.
+pub fn set_modular_scale(theme: Theme, scale: &ModularScale) -> Theme {
+    scale_steps()
+        .into_iter()
+        .fold(theme, |theme, (font_size, line_height, step, leading)| {
+            theme
+                .set_font_size(font_size, px(scale.step(step) as i32))
+                .set_line_height(
+                    line_height,
+                    CssLineHeight::Number(scale.line_height(step, leading)),
+                )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_multiply_the_base_by_the_ratio() {
+        let scale = ModularScale::new(16., 1.333);
+        assert_eq!(scale.step(0), 16.);
+        assert_eq!(scale.step(1), 21.);
+        assert_eq!(scale.step(2), 28.);
+        assert_eq!(scale.step(3), 38.);
+        assert_eq!(scale.step(-1), 12.);
+    }
+
+    #[test]
+    fn line_heights_snap_up_to_the_baseline_grid() {
+        let scale = ModularScale::new(16., 1.333);
+        // 16px * 1.6 = 25.6px rounds up to 28px
+        assert_eq!(scale.line_height(0, 1.6), 28. / 16.);
+        // 38px * 1.2 = 45.6px rounds up to 48px
+        assert_eq!(scale.line_height(3, 1.2), 48. / 38.);
+    }
+
+    #[test]
+    fn line_heights_on_the_grid_are_kept() {
+        let scale = ModularScale::new(20., 1.5);
+        assert_eq!(scale.line_height(0, 1.6), 1.6);
+    }
+
+    #[test]
+    fn every_line_is_a_whole_number_of_grid_steps() {
+        let scale = ModularScale::new(16., 1.333);
+        for &(step, leading) in &[(3, 1.2), (2, 1.25), (1, 1.3), (0, 1.6), (-1, 1.4)] {
+            let line = scale.line_height(step, leading) * scale.step(step);
+            assert!((line / BASELINE_GRID - (line / BASELINE_GRID).round()).abs() < 1e-9);
+            assert!(line >= scale.step(step) * leading);
+        }
+    }
+}
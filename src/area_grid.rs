@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+use std::fmt;
+
+// Area grids
+// ----------
+//
+// Grids of area names as written in the layout playground and in layout files. Like
+// `grid-template-areas` there is one row per line, rows can also be separated by `/`:
+//
+//     header header / sidebar main / footer footer
+//
+// Names are letters, digits, `_` and `-`, starting with a letter or `_`. A `.` is a cell that belongs
+// to no area. Like css grids every row has the same number of cells and every area is a rectangle.
+
+pub const EMPTY_CELL: &str = ".";
+
+#[derive(Debug, PartialEq)]
+pub struct Grid {
+    rows: Vec<Vec<String>>,
+}
+
+// Rows are numbered from 1, as they are read.
+#[derive(Debug, PartialEq)]
+pub enum GridError {
+    NoRows,
+    EmptyRow { row: usize },
+    InvalidName { row: usize, name: String },
+    RowLength { row: usize, found: usize, expected: usize },
+    NotRectangular { name: String },
+    SameVariant { first: String, second: String, variant: String },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::NoRows => write!(f, "The grid has no rows."),
+            GridError::EmptyRow { row } => write!(f, "Row {} has no areas.", row),
+            GridError::InvalidName { row, name } => write!(
+                f,
+                "Row {}: `{}` is not an area name, use letters, digits, `_` and `-`, or `.` for an empty cell.",
+                row, name
+            ),
+            GridError::RowLength { row, found, expected } => write!(
+                f,
+                "Row {} has {} cells but row 1 has {}, every row needs the same number.",
+                row, found, expected
+            ),
+            GridError::NotRectangular { name } => {
+                write!(f, "The `{}` area is not a rectangle.", name)
+            }
+            GridError::SameVariant { first, second, variant } => write!(
+                f,
+                "`{}` and `{}` would both be the `{}` variant, rename one of them.",
+                first, second, variant
+            ),
+        }
+    }
+}
+
+impl Grid {
+    pub fn parse(text: &str) -> Result<Grid, GridError> {
+        let mut rows: Vec<Vec<String>> = vec![];
+
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            for cells in line.split('/') {
+                let row = rows.len() + 1;
+                let cells = cells
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect::<Vec<_>>();
+                if cells.is_empty() {
+                    return Err(GridError::EmptyRow { row });
+                }
+                if let Some(name) = cells.iter().find(|name| !is_valid_name(name)) {
+                    return Err(GridError::InvalidName { row, name: name.clone() });
+                }
+                if let Some(first) = rows.first() {
+                    if cells.len() != first.len() {
+                        return Err(GridError::RowLength {
+                            row,
+                            found: cells.len(),
+                            expected: first.len(),
+                        });
+                    }
+                }
+                rows.push(cells);
+            }
+        }
+        if rows.is_empty() {
+            return Err(GridError::NoRows);
+        }
+
+        let grid = Grid { rows };
+        grid.check_rectangles()?;
+        Ok(grid)
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    // Every area name once, in reading order.
+    pub fn areas(&self) -> Vec<&str> {
+        let mut areas: Vec<&str> = vec![];
+        for name in self.rows.iter().flatten() {
+            if name != EMPTY_CELL && !areas.contains(&name.as_str()) {
+                areas.push(name);
+            }
+        }
+        areas
+    }
+
+    pub fn has_empty_cells(&self) -> bool {
+        self.rows.iter().flatten().any(|name| name == EMPTY_CELL)
+    }
+
+    fn check_rectangles(&self) -> Result<(), GridError> {
+        for name in self.areas() {
+            let cells = self
+                .rows
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(move |(_, cell)| *cell == name)
+                        .map(move |(x, _)| (x, y))
+                })
+                .collect::<Vec<_>>();
+            let left = cells.iter().map(|(x, _)| *x).min().unwrap_or(0);
+            let right = cells.iter().map(|(x, _)| *x).max().unwrap_or(0);
+            let top = cells.iter().map(|(_, y)| *y).min().unwrap_or(0);
+            let bottom = cells.iter().map(|(_, y)| *y).max().unwrap_or(0);
+
+            // a rectangle fills its bounding box
+            if cells.len() != (right - left + 1) * (bottom - top + 1) {
+                return Err(GridError::NotRectangular { name: name.to_string() });
+            }
+        }
+        Ok(())
+    }
+
+    // Names only differing in case or separators, `main-area` and `main_area`, can not both be
+    // exported as variants by `to_rust`.
+    pub fn check_variants(&self) -> Result<(), GridError> {
+        let mut variants: HashMap<String, &str> = HashMap::new();
+        if self.has_empty_cells() {
+            variants.insert(variant_name(EMPTY_CELL), EMPTY_CELL);
+        }
+        for name in self.areas() {
+            let variant = variant_name(name);
+            if let Some(first) = variants.insert(variant.clone(), name) {
+                return Err(GridError::SameVariant {
+                    first: first.to_string(),
+                    second: name.to_string(),
+                    variant,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    // Rust code for the same layout, an area enum and a `Layout` using it. Empty track and gap
+    // settings are left out.
+    pub fn to_rust(&self, columns: &str, rows: &str, gap: &str) -> String {
+        let mut code = String::from("#[derive(Hash, PartialEq, Eq, Clone, Debug)]\nenum Area {\n");
+        for name in self.areas() {
+            code.push_str(&format!("    {},\n", variant_name(name)));
+        }
+        if self.has_empty_cells() {
+            code.push_str("    Empty,\n");
+            code.push_str(
+                "}\n\nimpl LayoutArea for Area {\n    fn is_empty(&self) -> bool {\n        *self == Area::Empty\n    }\n}\n",
+            );
+        } else {
+            code.push_str("}\n\nimpl LayoutArea for Area {}\n");
+        }
+
+        code.push_str("\nfn layout() -> Layout<Area> {\n    use Area::*;\n    Layout::areas(&[\n");
+        for row in &self.rows {
+            let variants = row
+                .iter()
+                .map(|name| variant_name(name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            code.push_str(&format!("        &[{}],\n", variants));
+        }
+        code.push_str("    ])\n");
+
+        let style = [
+            ("grid_template_columns", columns),
+            ("grid_template_rows", rows),
+            ("grid_gap", gap),
+        ]
+        .iter()
+        .filter(|(_, value)| !value.trim().is_empty())
+        .map(|(property, value)| format!(".{}({:?})", property, value.trim()))
+        .collect::<Vec<_>>();
+        if !style.is_empty() {
+            code.push_str(&format!("    .style(s(){})\n", style.join("")));
+        }
+        code.push_str("}\n");
+        code
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    if name == EMPTY_CELL {
+        return true;
+    }
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+// The enum variant for an area name, `main-content` is `MainContent` and `.` is `Empty`.
+pub fn variant_name(name: &str) -> String {
+    if name == EMPTY_CELL {
+        return "Empty".to_string();
+    }
+    name.split(|c| c == '-' || c == '_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(text: &str) -> Vec<Vec<String>> {
+        Grid::parse(text).unwrap().rows().to_vec()
+    }
+
+    #[test]
+    fn rows_are_split_on_slashes_and_lines() {
+        let expected = vec![
+            vec!["header".to_string(), "header".to_string()],
+            vec!["sidebar".to_string(), "main".to_string()],
+        ];
+        assert_eq!(rows("header header / sidebar main"), expected);
+        assert_eq!(rows("header header\n\n  sidebar   main\n"), expected);
+    }
+
+    #[test]
+    fn areas_are_listed_once_in_reading_order() {
+        let grid = Grid::parse("header header / sidebar main / . footer").unwrap();
+        assert_eq!(grid.areas(), vec!["header", "sidebar", "main", "footer"]);
+        assert!(grid.has_empty_cells());
+    }
+
+    #[test]
+    fn blank_grids_have_no_rows() {
+        assert_eq!(Grid::parse(" \n \n"), Err(GridError::NoRows));
+    }
+
+    #[test]
+    fn slashes_need_areas_between_them() {
+        assert_eq!(Grid::parse("a b / / c d"), Err(GridError::EmptyRow { row: 2 }));
+        assert_eq!(Grid::parse("a b /"), Err(GridError::EmptyRow { row: 2 }));
+    }
+
+    #[test]
+    fn names_must_be_identifiers() {
+        assert_eq!(
+            Grid::parse("a b / c 1up"),
+            Err(GridError::InvalidName { row: 2, name: "1up".to_string() })
+        );
+        assert_eq!(
+            Grid::parse("a ..").unwrap_err(),
+            GridError::InvalidName { row: 1, name: "..".to_string() }
+        );
+        assert!(Grid::parse("_side main-content area_2").is_ok());
+    }
+
+    #[test]
+    fn rows_must_be_the_same_length() {
+        assert_eq!(
+            Grid::parse("a b / c d e"),
+            Err(GridError::RowLength { row: 2, found: 3, expected: 2 })
+        );
+    }
+
+    #[test]
+    fn areas_must_be_rectangles() {
+        assert!(Grid::parse("a a / a a / b b").is_ok());
+        assert_eq!(
+            Grid::parse("a a / a b"),
+            Err(GridError::NotRectangular { name: "a".to_string() })
+        );
+        assert_eq!(
+            Grid::parse("a b a"),
+            Err(GridError::NotRectangular { name: "a".to_string() })
+        );
+    }
+
+    #[test]
+    fn names_with_the_same_variant_are_rejected() {
+        let check = |text| Grid::parse(text).unwrap().check_variants();
+        assert_eq!(
+            check("main-area main_area"),
+            Err(GridError::SameVariant {
+                first: "main-area".to_string(),
+                second: "main_area".to_string(),
+                variant: "MainArea".to_string(),
+            })
+        );
+        assert!(matches!(check("empty ."), Err(GridError::SameVariant { .. })));
+    }
+
+    #[test]
+    fn variant_names_are_camel_case() {
+        assert_eq!(variant_name("header"), "Header");
+        assert_eq!(variant_name("main-content"), "MainContent");
+        assert_eq!(variant_name("_side_bar"), "SideBar");
+        assert_eq!(variant_name("."), "Empty");
+    }
+
+    #[test]
+    fn exports_rust_layout_code() {
+        let grid = Grid::parse("header header / sidebar main").unwrap();
+        assert_eq!(
+            grid.to_rust("200px 1fr", "", "8px"),
+            r#"#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+enum Area {
+    Header,
+    Sidebar,
+    Main,
+}
+
+impl LayoutArea for Area {}
+
+fn layout() -> Layout<Area> {
+    use Area::*;
+    Layout::areas(&[
+        &[Header, Header],
+        &[Sidebar, Main],
+    ])
+    .style(s().grid_template_columns("200px 1fr").grid_gap("8px"))
+}
+"#
+        );
+    }
+
+    #[test]
+    fn exported_empty_cells_use_an_empty_variant() {
+        let code = Grid::parse("a .").unwrap().to_rust("", "", "");
+        assert!(code.contains("    A,\n    Empty,\n}"));
+        assert!(code.contains("*self == Area::Empty"));
+        assert!(code.contains("&[A, Empty],"));
+        assert!(!code.contains(".style("));
+    }
+}
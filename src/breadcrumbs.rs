@@ -0,0 +1,179 @@
+use crate::app_styling::theme::*;
+use crate::{header, nav, Model, Msg};
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::px;
+use seed_style::*;
+
+// Breadcrumbs
+// -----------
+//
+// Shown in the header's empty middle area as Section › Page › current heading. The section and page
+// come from the nav drawer's sections, the heading is the last h2/h3 anchor scrolled past the top
+// of the window, or past the header while it is still in view, see `anchors.rs`.
+//
+// Long crumbs are cut short with an ellipsis within the area, the heading first, and at
+// `Breakpoint::Small` the section is left out. At `Breakpoint::ExtraSmall` the header has no room
+// for them.
+
+// The heading the reader is currently in.
+#[derive(Clone, PartialEq, Debug)]
+struct CurrentHeading {
+    id: String,
+    text: String,
+}
+
+#[atom]
+fn current_heading() -> Option<CurrentHeading> {
+    None
+}
+
+// Called on page changes, the new page starts above its first heading.
+pub fn clear_heading() {
+    current_heading().set(None);
+}
+
+// Re-renders the breadcrumbs when scrolling past a heading changes the current one.
+pub fn on_window_scroll() -> Option<Msg> {
+    let heading = heading_at_top();
+    if heading == current_heading().get() {
+        return None;
+    }
+    current_heading().set(heading);
+    Some(Msg::NoOp)
+}
+
+// The last anchored heading whose top has scrolled up to the bottom of the header, or to the top
+// of the window once the header has scrolled away.
+fn heading_at_top() -> Option<CurrentHeading> {
+    let header_bottom =
+        (f64::from(header::HEADER_HEIGHT) - window().page_y_offset().unwrap_or(0.)).max(0.);
+
+    let headings = document()
+        .query_selector_all(".prose h2.anchored, .prose h3.anchored")
+        .ok()?;
+    let mut current = None;
+    for index in 0..headings.length() {
+        let heading = match headings
+            .item(index)
+            .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+        {
+            Some(heading) => heading,
+            None => continue,
+        };
+        // headings are in document order, so stop at the first one still below the header
+        if heading.get_bounding_client_rect().top() > header_bottom + 16. {
+            break;
+        }
+        current = Some(heading);
+    }
+
+    current.map(|heading| CurrentHeading {
+        id: heading.id(),
+        // leaves out the text of the `#` permalink
+        text: heading
+            .text_content()
+            .unwrap_or_default()
+            .trim_end_matches('#')
+            .trim()
+            .to_string(),
+    })
+}
+
+pub fn view(model: &Model) -> Node<Msg> {
+    let page = model.page.get();
+    let (section, link) = match nav::sections().into_iter().find_map(|section| {
+        let title = section.title;
+        section
+            .links
+            .into_iter()
+            .find(|link| link.page == page)
+            .map(|link| (title, link))
+    }) {
+        Some(found) => found,
+        None => return empty![],
+    };
+    let heading = current_heading().get();
+
+    nav![
+        class!["breadcrumbs"],
+        attrs! {At::from("aria-label") => "Breadcrumb"},
+        ol![
+            li![class!["breadcrumb-section"], span![section]],
+            li![
+                class!["breadcrumb-page"],
+                a![
+                    attrs! {At::Href => link.href},
+                    if heading.is_none() {
+                        Some(attrs! {At::from("aria-current") => "page"})
+                    } else {
+                        None
+                    },
+                    link.label,
+                ]
+            ],
+            heading.map(|heading| {
+                li![
+                    class!["breadcrumb-heading"],
+                    a![
+                        attrs! {
+                            At::Href => format!("{}#{}", link.href, heading.id),
+                            At::from("aria-current") => "location",
+                        },
+                        heading.text,
+                    ]
+                ]
+            }),
+        ]
+    ]
+}
+
+#[reaction]
+pub fn breadcrumb_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(".breadcrumbs", s().min_width(px(0)).px(px(16)))
+            .style(
+                ".breadcrumbs ol",
+                s().min_width(px(0))
+                    .display_flex()
+                    .align_items_center()
+                    .m(px(0))
+                    .p(px(0))
+                    .list_style_type_none()
+                    .font_size(FontSize::Caption)
+                    .color(Color::MutedText),
+            )
+            .style(
+                ".breadcrumbs li",
+                s().min_width(px(0))
+                    .overflow_hidden()
+                    .white_space("nowrap")
+                    .raw("text-overflow: ellipsis;"),
+            )
+            // the separator stays whole however much the crumb before it shrinks
+            .style(
+                ".breadcrumbs li + li::before",
+                s().raw("content: \"›\";").px(px(6)),
+            )
+            .style(".breadcrumbs a", s().color(Color::MutedText).text_decoration_none())
+            .style(".breadcrumbs a", s().hover().color(Color::Primary))
+            .style(".breadcrumb-section", s().flex_shrink("1"))
+            .style(".breadcrumb-page", s().flex_shrink("1"))
+            // the heading is usually the longest crumb and gives way first
+            .style(
+                ".breadcrumb-heading",
+                s().flex_shrink("4").color(Color::MainText),
+            )
+            .style(".breadcrumb-heading a", s().color(Color::MainText))
+            .style(
+                ".breadcrumb-section",
+                s().only_and_below(Breakpoint::Small).display_none(),
+            )
+            .style(
+                ".breadcrumbs li.breadcrumb-page::before",
+                s().only_and_below(Breakpoint::Small).raw("content: none;"),
+            )
+            .activate_styles()
+    });
+}
@@ -0,0 +1,129 @@
+use crate::app_styling::theme::*;
+use crate::commands::{self, Command, CommandGroup};
+use crate::shortcuts::{self, Shortcut};
+use crate::Msg;
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::px;
+use seed_style::*;
+use std::cell::Cell;
+
+// Breakpoint badge
+// ----------------
+//
+// A badge in the corner of the window with the active `Breakpoint` and the window width, for tuning
+// `only_and_above` and `only_and_below` styles.
+//
+// Resizes within a breakpoint skip rendering (see `update` in `lib.rs`), so the badge keeps the width
+// in its own state, updated from its own resize handler, which re-renders while the badge is shown.
+//
+// Debug builds always have the badge. Release builds only have it when the page is opened with
+// `?breakpoints` in its url. Where it is available it can be toggled with `b` or from the command palette.
+
+const URL_FLAG: &str = "breakpoints";
+
+thread_local! {
+    static AVAILABLE: Cell<bool> = Cell::new(cfg!(debug_assertions));
+}
+
+#[atom]
+fn badge_shown() -> bool {
+    true
+}
+
+#[atom]
+fn window_width() -> u32 {
+    inner_width()
+}
+
+fn inner_width() -> u32 {
+    window()
+        .inner_width()
+        .ok()
+        .and_then(|width| width.as_f64())
+        .unwrap_or(0.) as u32
+}
+
+// Keeps the badge's width current, called on every window resize.
+pub fn on_window_resize() -> Option<Msg> {
+    if !available() {
+        return None;
+    }
+    window_width().set(inner_width());
+    if badge_shown().get() {
+        Some(Msg::NoOp)
+    } else {
+        None
+    }
+}
+
+// Called with the url the app starts at.
+pub fn enable_from_url(url: &Url) {
+    if url.search().get(URL_FLAG).is_some() {
+        AVAILABLE.with(|available| available.set(true));
+    }
+}
+
+fn available() -> bool {
+    AVAILABLE.with(Cell::get)
+}
+
+fn toggle() -> Msg {
+    badge_shown().update(|shown| *shown = !*shown);
+    Msg::NoOp
+}
+
+pub fn register_commands() {
+    if available() {
+        commands::register(Command::new(
+            CommandGroup::Action,
+            "Toggle breakpoint badge",
+            toggle,
+        ));
+    }
+}
+
+pub fn register_shortcuts() {
+    if available() {
+        shortcuts::register(Shortcut::new(&["b"], "Toggle breakpoint badge", toggle));
+    }
+}
+
+pub fn view() -> Node<Msg> {
+    if !available() || !badge_shown().get() {
+        return empty![];
+    }
+    let width = window_width().get();
+
+    div![
+        class!["breakpoint-badge"],
+        attrs! {At::Title => "Active breakpoint and window width, press b to hide"},
+        format!("{:?} · {}px", breakpoint_for_width(width), width),
+        mouse_ev(Ev::Click, |_| toggle()),
+    ]
+}
+
+#[reaction]
+pub fn breakpoint_badge_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(
+                ".breakpoint-badge",
+                s().position_fixed()
+                    .right(px(8))
+                    .bottom(px(8))
+                    .z_index("9")
+                    .px(px(8))
+                    .py(px(4))
+                    .radius(px(4))
+                    .font_family("monospace")
+                    .font_size(FontSize::Caption)
+                    .color(Color::Background)
+                    .bg_color(Color::DarkSecondary)
+                    .opacity("0.85")
+                    .cursor_pointer(),
+            )
+            .style(".breakpoint-badge", s().media("@media print").display_none())
+            .activate_styles()
+    });
+}
@@ -8,7 +8,7 @@ use seed_style::*;
 pub fn view(model: &Model) -> Node<Msg> {
     render_centred_article(model, |_| {
         div![
-            h1!["Button Styling"],
+            prose!["# Button Styling"],
         
             p![
                 r#"Using Seed Style we can apply styles in many different ways. This page demonstrates some applications that include 
@@ -31,14 +31,14 @@ fn unstyled_counter() -> Node<Msg> {
 
     div![
         s().display_flex().flex_direction_column(),
-        h2!["Unstyled Button"],
+        prose!["## Unstyled Button"],
         button![
             "Clicked (",
             counter,
             ") times",
             counter.on_click(|v| *v += 1)
         ],
-        md![
+        prose![
             r#"This is a unstyled button, it uses seed hooks to store state with `use_state(|| 0)`.
                 The counter is incremented with `counter.on_click(|v| *v += 1)` which creates an `Ev::OnClick` EventHandler.
                 All the other buttons in this page use this simple pattern.
@@ -58,7 +58,7 @@ fn basic_styled_counter() -> Node<Msg> {
     let counter = use_state(|| 0);
     div![
         s().display_flex().flex_direction_column(),
-        h2!["Basic Styled Button"],
+        prose!["## Basic Styled Button"],
         button![
             s().padding_left(px(24))
                 .padding_right(px(24))
@@ -73,7 +73,7 @@ fn basic_styled_counter() -> Node<Msg> {
             ") times",
             counter.on_click(move |v| *v += 1)
         ],
-        md![
+        prose![
             r#"This is a basic styled button, the button is styled with the following:
 
 ```rust
@@ -123,7 +123,7 @@ fn css_styled_counter() -> Node<Msg> {
 
     div![
         s().display_flex().flex_direction_column(),
-        h2!["Raw CSS Styled Button"],
+        prose!["## Raw CSS Styled Button"],
         button![
             button_style,
             "Clicked (",
@@ -131,7 +131,7 @@ fn css_styled_counter() -> Node<Msg> {
             ") times",
             counter.on_click(|v| *v += 1)
         ],
-        md![
+        prose![
             r##"This is a button styled with css directly using the `.raw()` method.  We can include any css that can be used inside a style block. In this case we have used:
 
 ```rust
@@ -171,8 +171,8 @@ fn convenience_styled_counter() -> Node<Msg> {
 
     div![
         s().display_flex().flex_direction_column(),
-        h2!["Styled with shorter convenience methods"],
-        md![
+        prose!["## Styled with shorter convenience methods"],
+        prose![
             r#"There are convenience methods available  in order to make defining styles more efficient.
 For instance `pl()` for `padding-left()`. Horizontal/ vertical padding and margins can be set with `.mx()`, `py()` etc.
 
@@ -227,7 +227,7 @@ fn hover_counter() -> Node<Msg> {
 
     div![
         s().display_flex().flex_direction_column(),
-        h2!["Hover and other Pseudo-selectors styled buttons"],
+        prose!["## Hover and other Pseudo-selectors styled buttons"],
         button![
             button_style,
             hover_style,
@@ -237,7 +237,7 @@ fn hover_counter() -> Node<Msg> {
             ") times",
             counter.on_click(|v| *v += 1)
         ],
-        md![
+        prose![
             r#" Unlike direct inline styles, you can correctly use pseudo-selectors
 to style things like `:hover` status.  Simply use the pseudo selector name as a method `.hover()` method on a style.  Please note that this 
 sets all properties defined in that style object to be affected by `:hover`.
@@ -276,7 +276,7 @@ fn media_query_counter() -> Node<Msg> {
 
     div![
         s().display_flex().flex_direction_column(),
-        h2!["Media Query Styled Button - shrink width to less than 700px"],
+        prose!["## Media Query Styled Button - shrink width to less than 700px"],
         button![
             button_style,
             media_query,
@@ -285,7 +285,7 @@ fn media_query_counter() -> Node<Msg> {
             ") times",
             counter.on_click(|v| *v += 1)
         ],
-        md![r#"
+        prose![r#"
 Media queries can be used in a number of ways.  The most basic way is by using the `media()` method on a style. This will 
 result in that style being nested within a media query block.
 
@@ -317,7 +317,7 @@ fn variant_counter() -> Node<Msg> {
 
     div![
         s().display_flex().flex_direction_column(),
-        h2!["Style Variants"]
+        prose!["## Style Variants"]
 
         ,
         button![
@@ -332,7 +332,7 @@ fn variant_counter() -> Node<Msg> {
             ") times",
             counter.on_click(|v| *v += 1)
         ],
-        md![r#"
+        prose![r#"
 Due to the flexibility of writing rust in view code variants of styles can be trivially implemented.
 
 For instance we can have a base button style:
@@ -377,7 +377,7 @@ fn styles_on_args_counter(user_style: seed_style::Style) -> Node<Msg> {
 
     div![
         s().display_flex().flex_direction_column(),
-        h2!["Styles passed as arguments"],
+        prose!["## Styles passed as arguments"],
         button![
             button_style,
             user_style,
@@ -386,7 +386,7 @@ fn styles_on_args_counter(user_style: seed_style::Style) -> Node<Msg> {
             ") times",
             counter.on_click(|v| *v += 1)
         ],
-        md![r#"
+        prose![r#"
 Because a style is just an object it can be passed in as the argument to a view function. e.g:
 
 ```
@@ -0,0 +1,178 @@
+use crate::app_styling::theme::*;
+use crate::highlight::Language;
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::px;
+use seed_style::*;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+
+// Code block toolbar
+// ------------------
+//
+// Each highlighted code block gets a toolbar with a language badge, a line number toggle
+// and a "Copy" button.
+//
+// Line numbers are rendered in a separate `aria-hidden` gutter that cannot be selected and the copy
+// button copies the original source, therefore copied code never includes line numbers.
+//
+// The toggle and the transient "Copied" state only flip a data attribute on the dom element, the styles
+// below do the rest, this means they need no component state even though blocks are decorated
+// outside of any component.
+
+pub fn decorate_code_block<Ms: 'static>(pre: &mut El<Ms>, language: Language, src: String) {
+    let line_count = src.lines().count();
+
+    let toolbar = div![
+        class!["hl-toolbar"],
+        span![class!["hl-language"], language.name()],
+        if line_count > 1 {
+            button![
+                class!["hl-toggle-lines"],
+                attrs! {At::Type => "button", At::Title => "Toggle line numbers"},
+                "#",
+                ev(Ev::Click, |event| toggle_line_numbers(&event)),
+            ]
+        } else {
+            empty![]
+        },
+        button![
+            class!["hl-copy"],
+            attrs! {At::Type => "button", At::from("aria-label") => "Copy code"},
+            span![class!["hl-copy-label"], "Copy"],
+            span![class!["hl-copied-label"], "Copied"],
+            ev(Ev::Click, move |event| copy_code(&event, &src)),
+        ]
+    ];
+
+    let gutter = span![
+        class!["hl-gutter"],
+        attrs! {At::from("aria-hidden") => "true"},
+        (1..=line_count)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    ];
+
+    pre.add_class("hl-block");
+    pre.children.insert(0, gutter);
+    pre.children.insert(0, toolbar);
+}
+
+fn toggle_line_numbers(event: &web_sys::Event) {
+    if let Some(button) = event_element(event) {
+        if let Ok(Some(pre)) = button.closest("pre") {
+            let _ = pre.toggle_attribute("data-numbered");
+        }
+    }
+}
+
+fn copy_code(event: &web_sys::Event, src: &str) {
+    if let (Some(button), Some(promise)) = (event_element(event), write_to_clipboard(src)) {
+        spawn_local(async move {
+            if JsFuture::from(promise).await.is_ok() {
+                show_copied(&button);
+            }
+        });
+    }
+}
+
+// Shows "Copied" on the button for a moment.
+fn show_copied(button: &web_sys::Element) {
+    let _ = button.set_attribute("data-copied", "");
+    let button = button.clone();
+    let reset = Closure::once_into_js(move || {
+        let _ = button.remove_attribute("data-copied");
+    });
+    let _ = window()
+        .set_timeout_with_callback_and_timeout_and_arguments_0(reset.unchecked_ref(), 1500);
+}
+
+fn event_element(event: &web_sys::Event) -> Option<web_sys::Element> {
+    event
+        .current_target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+}
+
+// `navigator.clipboard.writeText(text)`, the Clipboard api is one of web_sys' unstable apis
+// therefore it is looked up through `Reflect`. `None` if the browser does not provide it.
+fn write_to_clipboard(text: &str) -> Option<js_sys::Promise> {
+    let navigator = js_sys::Reflect::get(&window(), &"navigator".into()).ok()?;
+    let clipboard = js_sys::Reflect::get(&navigator, &"clipboard".into()).ok()?;
+    let write_text = js_sys::Reflect::get(&clipboard, &"writeText".into())
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?;
+    write_text
+        .call1(&clipboard, &text.into())
+        .ok()?
+        .dyn_into::<js_sys::Promise>()
+        .ok()
+}
+
+#[reaction]
+pub fn code_block_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(
+                ".hl-block",
+                s().position_relative()
+                    .display_flex()
+                    .flex_direction_row()
+                    .align_items_flex_start(),
+            )
+            .style(
+                ".hl-toolbar",
+                s().position_absolute()
+                    .top(px(4))
+                    .right(px(4))
+                    .display_flex()
+                    .align_items_center()
+                    .font_size(FontSize::Caption)
+                    .opacity("0.6")
+                    .transition("opacity 0.2s ease-out 0s"),
+            )
+            .style(".hl-block:hover .hl-toolbar", s().opacity("1"))
+            .style(
+                ".hl-language",
+                s().color(Color::MutedText)
+                    .text_transform("uppercase")
+                    .letter_spacing(rem(0.05))
+                    .mr(px(4)),
+            )
+            .style(
+                ".hl-toolbar button",
+                s().ml(px(4))
+                    .px(px(8))
+                    .py(px(2))
+                    .radius(px(2))
+                    .b_width(px(0))
+                    .color(Color::Primary)
+                    .bg_color(Color::MutedPrimary)
+                    .font_size(FontSize::Caption)
+                    .cursor_pointer(),
+            )
+            .style(
+                ".hl-toolbar button",
+                s().hover().color(Color::Background).bg_color(Color::Primary),
+            )
+            .style(".hl-copied-label", s().display_none())
+            .style(".hl-copy[data-copied] .hl-copy-label", s().display_none())
+            .style(".hl-copy[data-copied] .hl-copied-label", s().display_inline())
+            .style(
+                ".hl-gutter",
+                s().display_none()
+                    .pr(px(12))
+                    .text_align_right()
+                    .color(Color::MutedText)
+                    .user_select("none"),
+            )
+            // line up with the padding and border of `.prose pre > code`
+            .style(
+                ".prose pre > .hl-gutter",
+                s().pt(px(24)).font_size(&[px(14), px(18)]),
+            )
+            .style(".hl-block[data-numbered] .hl-gutter", s().display_inline_block())
+            .style(".hl-toolbar", s().media("@media print").display_none())
+            .activate_styles()
+    });
+}
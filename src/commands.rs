@@ -0,0 +1,156 @@
+use crate::Msg;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// Command registry
+// ----------------
+//
+// Commands are the entries of the command palette. Any module can add its own with `register`,
+// usually from a `register_commands()` function called in `init`.
+//
+// A command's action returns the `Msg` to send once it has run, `Msg::GoTo(..)` for commands that
+// navigate and `Msg::NoOp` for those that only need a re-render.
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CommandGroup {
+    Page,
+    Heading,
+    Action,
+}
+
+impl CommandGroup {
+    pub fn name(self) -> &'static str {
+        match self {
+            CommandGroup::Page => "Page",
+            CommandGroup::Heading => "Heading",
+            CommandGroup::Action => "Action",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Command {
+    pub group: CommandGroup,
+    pub title: String,
+    action: Rc<dyn Fn() -> Msg>,
+}
+
+impl Command {
+    pub fn new(
+        group: CommandGroup,
+        title: impl Into<String>,
+        action: impl Fn() -> Msg + 'static,
+    ) -> Command {
+        Command {
+            group,
+            title: title.into(),
+            action: Rc::new(action),
+        }
+    }
+
+    // A command that navigates to `link`, e.g. `/hooks_api#use_state`.
+    pub fn go_to(group: CommandGroup, title: impl Into<String>, link: impl Into<String>) -> Command {
+        let link = link.into();
+        Command::new(group, title, move || Msg::GoTo(link.clone()))
+    }
+
+    pub fn run(&self) -> Msg {
+        (self.action)()
+    }
+}
+
+thread_local! {
+    static COMMANDS: RefCell<Vec<Command>> = RefCell::new(vec![]);
+}
+
+pub fn register(command: Command) {
+    COMMANDS.with(|commands| commands.borrow_mut().push(command));
+}
+
+// Commands fuzzy matching `query`, best match first. An empty query lists every command.
+pub fn matching(query: &str, limit: usize) -> Vec<Command> {
+    COMMANDS.with(|commands| {
+        let commands = commands.borrow();
+        let mut matches = commands
+            .iter()
+            .filter_map(|command| fuzzy_score(query, &command.title).map(|score| (score, command)))
+            .collect::<Vec<_>>();
+        // `sort_by` is stable so equal matches stay in registration order
+        matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, command)| command.clone())
+            .collect()
+    })
+}
+
+// `None` unless every character of `query` appears in `text` in order, ignoring case and spaces.
+// Matches at the start of words and runs of consecutive characters score higher, skipped
+// characters score lower.
+fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text = text.chars().map(|c| c.to_ascii_lowercase()).collect::<Vec<_>>();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+
+    for wanted in query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+    {
+        let found = (next..text.len()).find(|index| text[*index] == wanted)?;
+
+        score += 1;
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        if previous.map_or(false, |previous| previous + 1 == found) {
+            score += 5;
+        }
+        score -= ((found - next) as i32).min(3);
+
+        previous = Some(found);
+        next = found + 1;
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_characters_in_order_ignoring_case_and_spaces() {
+        assert!(fuzzy_score("tgl", "Toggle theme").is_some());
+        assert!(fuzzy_score("TOG THEME", "Toggle theme").is_some());
+    }
+
+    #[test]
+    fn out_of_order_queries_do_not_match() {
+        assert_eq!(fuzzy_score("emt", "Toggle theme"), None);
+        assert_eq!(fuzzy_score("x", "Toggle theme"), None);
+    }
+
+    #[test]
+    fn word_starts_score_higher() {
+        assert!(fuzzy_score("t", "a theme") > fuzzy_score("t", "bathe"));
+    }
+
+    #[test]
+    fn consecutive_characters_score_higher() {
+        assert!(fuzzy_score("ab", "abxx") > fuzzy_score("ab", "axbx"));
+    }
+
+    #[test]
+    fn equal_matches_keep_their_registration_order() {
+        for title in &["Reopen", "Open b", "Open a"] {
+            register(Command::new(CommandGroup::Action, *title, || Msg::NoOp));
+        }
+        let titles = matching("open", 10)
+            .into_iter()
+            .map(|command| command.title)
+            .collect::<Vec<_>>();
+        assert_eq!(titles, vec!["Open b", "Open a", "Reopen"]);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{Model, Msg};
+use crate::app_styling::theme::*;
+use crate::{anchors, layouts, toc, Model, Msg};
 use seed::{prelude::*, *};
 use seed_hooks::*;
 use seed_style::px;
@@ -12,6 +13,16 @@ pub enum AppArea {
 
 impl LayoutArea for AppArea {}
 
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+enum ArticleArea {
+    Article,
+}
+
+impl LayoutArea for ArticleArea {}
+
+// The app shell keeps its own layout rather than `layouts::sidebar_left`. Below `Small` the nav is a
+// drawer fixed over the page, so it stays in a column of no width instead of being stacked above
+// the main content, and the two areas sit without a gap between them.
 #[topo::nested]
 pub fn main_with_sidebar(on_intro_page: bool) -> Composition<AppArea, Model, Msg, SeedBreakpoint> {
     use AppArea::*;
@@ -26,18 +37,56 @@ pub fn render_centred_article<F: Fn(&Model) -> Node<Msg> + 'static>(
     model: &Model,
     content: F,
 ) -> Node<Msg> {
-    Composition::with_layout(Layout::grid(
-        s().grid_template_columns("1fr minmax(0px,1000px) 1fr"),
-    ))
+    centred_article(model, content, false)
+}
+
+// A centred article with an "On this page" table of contents built from its h2 and h3 headings.
+pub fn render_centred_article_with_toc<F: Fn(&Model) -> Node<Msg> + 'static>(
+    model: &Model,
+    content: F,
+) -> Node<Msg> {
+    centred_article(model, content, true)
+}
+
+fn centred_article<F: Fn(&Model) -> Node<Msg> + 'static>(
+    model: &Model,
+    content: F,
+    with_toc: bool,
+) -> Node<Msg> {
+    use ArticleArea::*;
+
+    // the table of contents takes a column to the right of the article
+    let max_width = if with_toc { "1240px" } else { "1000px" };
+
+    Composition::with_layouts(&layouts::centred_article(Article, max_width))
     .add_style(s().style_descendant("button").align_self_center())
-    .add_child(move |model: &Model| {
+    // articles use the full page width when printed
+    .add_style(s().media("@media print").grid_template_columns("minmax(0px,1fr)"))
+    .set_content(Article, move |model: &Model| {
+        let mut article = content(model);
+
+        let headings = if with_toc {
+            article.add_class(toc::ARTICLE_CLASS);
+            anchors::add_heading_anchors(&mut article, &mut anchors::Slugger::new());
+            anchors::collect_headings(&article)
+        } else {
+            vec![]
+        };
+
         div![
             only_and_above(SeedBreakpoint::Small, || {model.show_drawer.set(false); empty![]}),
-            s().grid_column_start("2")
-                .grid_column_end("3")
-                .padding_x(3)
-                .padding_y(2),
-            content(model)
+            s().padding_y(2),
+            if with_toc {
+                s().only_and_above(Breakpoint::Small)
+                    .display_grid()
+                    .grid_template_columns("minmax(0px,1fr) 220px")
+                    .column_gap(px(32))
+            } else {
+                s()
+            },
+            toc::dropdown(&headings),
+            article,
+            toc::aside(&headings),
         ]
     })
     .render(model)
@@ -0,0 +1,144 @@
+use crate::app_styling::theme::*;
+use crate::{Model, Msg};
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::px;
+use seed_style::*;
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+// Device previews
+// ---------------
+//
+// Renders a view inside a frame the width of a phone, tablet or desktop screen, so that the layouts
+// of several breakpoints can be compared side by side without resizing the window.
+//
+// Compositions pick their layout from the window width, which is the same for every frame. Views that
+// should follow the frame instead build their compositions with `device_preview::with_layouts`, as
+// `header::view` does, which picks the layout for the width of the frame being rendered, nested
+// compositions included. Outside a frame it is `Composition::with_layouts`.
+//
+// Only the choice of layout follows the frame, responsive styles such as `only_and_above` are media
+// queries and still follow the window. Pages showing previews say so.
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Device {
+    Phone,
+    Tablet,
+    Desktop,
+}
+
+impl Device {
+    pub fn name(self) -> &'static str {
+        match self {
+            Device::Phone => "Phone",
+            Device::Tablet => "Tablet",
+            Device::Desktop => "Desktop",
+        }
+    }
+
+    // Screen width in css pixels.
+    pub fn width(self) -> u32 {
+        match self {
+            Device::Phone => 375,
+            Device::Tablet => 768,
+            Device::Desktop => 1280,
+        }
+    }
+}
+
+thread_local! {
+    // Width of the frame currently being rendered.
+    static FRAME_WIDTH: Cell<Option<u32>> = Cell::new(None);
+}
+
+// `Composition::with_layouts`, choosing the layout by the width of the enclosing frame if there is one.
+pub fn with_layouts<A>(mut layouts: Vec<(Breakpoint, Layout<A>)>) -> Composition<A, Model, Msg, Breakpoint>
+where
+    A: LayoutArea + Hash + Eq + Clone + Debug + 'static,
+{
+    match FRAME_WIDTH.with(Cell::get).and_then(|width| layout_for_width(&layouts, width)) {
+        Some(index) => Composition::with_layout(layouts.swap_remove(index).1),
+        None => Composition::with_layouts(&layouts),
+    }
+}
+
+// Like a composition, the index of the layout of the largest breakpoint that `width` has reached or,
+// when there is none, of the smallest breakpoint. `None` when there are no layouts.
+fn layout_for_width<A>(layouts: &[(Breakpoint, Layout<A>)], width: u32) -> Option<usize> {
+    let lower_bound = |index: &usize| breakpoint_range(&layouts[*index].0).0;
+    let (reached, above): (Vec<usize>, Vec<usize>) =
+        (0..layouts.len()).partition(|index| lower_bound(index) <= width);
+
+    reached
+        .into_iter()
+        .max_by_key(lower_bound)
+        .or_else(|| above.into_iter().min_by_key(lower_bound))
+}
+
+// `view` rendered in a frame the width of `device`.
+pub fn frame(model: &Model, device: Device, view: impl Fn(&Model) -> Node<Msg>) -> Node<Msg> {
+    let width = device.width();
+
+    // compositions rendered by `view` read the width, the previous one is kept for nested frames
+    let outer_width = FRAME_WIDTH.with(|frame_width| frame_width.replace(Some(width)));
+    let content = view(model);
+    FRAME_WIDTH.with(|frame_width| frame_width.set(outer_width));
+
+    figure![
+        class!["device-frame"],
+        figcaption![format!(
+            "{} · {}px · {:?}",
+            device.name(),
+            width,
+            breakpoint_for_width(width)
+        )],
+        div![class!["device-screen"], s().w(px(width)), content],
+    ]
+}
+
+// A frame for each device, side by side.
+pub fn frames(model: &Model, devices: &[Device], view: impl Fn(&Model) -> Node<Msg>) -> Node<Msg> {
+    div![
+        class!["device-frames"],
+        devices
+            .iter()
+            .map(|device| frame(model, *device, &view))
+            .collect::<Vec<_>>(),
+    ]
+}
+
+#[reaction]
+pub fn device_preview_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(
+                ".device-frames",
+                s().display_flex()
+                    .align_items_flex_start()
+                    .grid_gap(px(24))
+                    .overflow_x_auto()
+                    .py(px(16)),
+            )
+            .style(".device-frame", s().m(px(0)).flex_shrink("0"))
+            .style(
+                ".device-frame figcaption",
+                s().mb(px(8))
+                    .font_size(FontSize::Caption)
+                    .color(Color::MutedText),
+            )
+            .style(
+                ".device-screen",
+                s().raw("box-sizing: content-box;")
+                    .max_height(px(720))
+                    .overflow_auto()
+                    .radius(px(12))
+                    .b_width(px(8))
+                    .b_style_solid()
+                    .b_color(Color::MutedSecondary)
+                    .bg_color(Color::Background),
+            )
+            .activate_styles()
+    });
+}
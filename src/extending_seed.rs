@@ -365,10 +365,10 @@ root![
 }
 
 pub fn view(model: &Model) -> Node<Msg> {
-    render_centred_article(model, |_| {
+    render_centred_article_with_toc(model, |_| {
         div![
-            h1!["Extending Seed"],
-            md![r##"
+            prose!["# Extending Seed"],
+            prose![r##"
 
 Seed Style provides us with an opportunity to extend Seed's macro language in sensible and expressive ways. 
 For instance a common pattern to ensure completely centred content is the flex styled div:
@@ -524,7 +524,7 @@ And can be fully styled as desired:
                     ]
                 ]]
             },
-            md![r#"
+            prose![r#"
 
 To create this re-usable component we write the following view function:
 
@@ -667,7 +667,7 @@ uglycard![
                 orange_side![p!["This is the orange side!"]],
                 blue_side![p!["This is the blue side!"]],
             ]],
-        md![r#"
+        prose![r#"
 ## Optional Arguments
 
 Sometimes one may wish to configure a custom component for use. For instance in the above example
@@ -726,7 +726,7 @@ warning![
         ]
     }
     ,
-    md![r#"
+    prose![r#"
 ## View Lists
 
 Sometimes one may wish to allow multiple items within a custom component.  For instance a `ul` tag can contain many `li` items.
@@ -788,7 +788,7 @@ todos![
 
 }
 ,
-md![r#"
+prose![r#"
 ## Optional arguments to child nodes
 
 It is also possible to have optional arguments to each labelled child block. In the previous example a different 
@@ -918,7 +918,7 @@ cols_todos![
     },
 ]
 },
-md![
+prose![
     r##"
 ## Putting it all together
 
@@ -0,0 +1,157 @@
+use crate::app_styling::theme::*;
+use crate::{header, Model, Msg};
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::px;
+use seed_style::*;
+
+// Site footer
+// -----------
+//
+// The footer of every page, with links to the project, the versions of the Seed crates the site is
+// built with, credits for the themes and the date the site was last updated.
+//
+// Versions and the date are read at compile time by `build.rs`, from `Cargo.lock` (or `Cargo.toml`
+// when the build has no lock file) and the last git commit. Builds from a source archive, without
+// git, leave the date out.
+
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+enum Area {
+    Links,
+    Versions,
+    Credits,
+}
+
+impl LayoutArea for Area {}
+
+const LINKS: &[(&str, &str)] = &[
+    ("Seed", "https://seed-rs.org"),
+    ("Seed on GitHub", "https://github.com/seed-rs/seed"),
+    ("Style quickstart", "https://github.com/rebo/seed-style-quickstart-basic"),
+    ("Hooks quickstart", "https://github.com/rebo/seed-hooks-quickstart"),
+];
+
+// Crates by their name on crates.io, with the version they are built with.
+const CRATES: &[(&str, Option<&str>)] = &[
+    ("seed", option_env!("SEED_VERSION")),
+    ("seed_hooks", option_env!("SEED_HOOKS_VERSION")),
+    ("seed_style_preview", option_env!("SEED_STYLE_VERSION")),
+];
+
+const LAST_UPDATED: Option<&str> = option_env!("LAST_UPDATED");
+
+pub fn view(model: &Model) -> Node<Msg> {
+    use Area::*;
+    use Breakpoint::*;
+
+    footer![
+        class!["site-footer"],
+        Composition::with_layouts(&[
+            (
+                Small,
+                Layout::areas(&[&[Links, Versions, Credits]]).style(
+                    s().grid_template_columns("repeat(3, minmax(0px, 1fr))")
+                        .grid_gap(px(24))
+                        .px(px(24))
+                        .py(px(32)),
+                ),
+            ),
+            // one centred column, clear of the home indicator of phones
+            (
+                ExtraSmall,
+                Layout::areas(&[&[Links], &[Versions], &[Credits]]).style(
+                    s().grid_gap(px(16))
+                        .px(px(16))
+                        .pt(px(24))
+                        .pb("calc(24px + env(safe-area-inset-bottom))")
+                        .text_align_center(),
+                ),
+            ),
+        ])
+        .set_content(Links, |_| links())
+        .set_content(Versions, |_| versions())
+        .set_content(Credits, |_| credits())
+        .render(model),
+    ]
+}
+
+fn links() -> Node<Msg> {
+    div![
+        h2!["Project"],
+        ul![LINKS
+            .iter()
+            .map(|(name, href)| li![a![attrs! {At::Href => href}, name]])
+            .collect::<Vec<_>>()],
+    ]
+}
+
+fn versions() -> Node<Msg> {
+    div![
+        h2!["Built with"],
+        ul![CRATES
+            .iter()
+            .map(|(name, version)| match version {
+                Some(version) => li![a![
+                    attrs! {At::Href => format!("https://crates.io/crates/{}/{}", name, version)},
+                    format!("{} {}", name, version),
+                ]],
+                None => li![a![attrs! {At::Href => format!("https://crates.io/crates/{}", name)}, name]],
+            })
+            .collect::<Vec<_>>()],
+    ]
+}
+
+fn credits() -> Node<Msg> {
+    let theme = if header::using_dark_theme().get() {
+        "Dark theme"
+    } else {
+        "Light theme"
+    };
+
+    div![
+        h2!["Credits"],
+        p![
+            theme,
+            " following the ",
+            a![attrs! {At::Href => "https://theme-ui.com/theme-spec/"}, "Theme Specification"],
+            ", with breakpoints from ",
+            a![attrs! {At::Href => "https://material-ui.com/customization/breakpoints/"}, "Material-UI"],
+            ".",
+        ],
+        LAST_UPDATED.map(|date| p![
+            "Last updated ",
+            time![attrs! {At::from("datetime") => date}, date],
+            ".",
+        ]),
+    ]
+}
+
+#[reaction]
+pub fn footer_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(
+                ".site-footer",
+                s().b_style_solid()
+                    .b_color(Color::MutedSecondary)
+                    .raw("border-width: 1px 0 0 0;")
+                    .font_size(FontSize::Caption)
+                    .color(Color::MutedText),
+            )
+            .style(
+                ".site-footer h2",
+                s().m(px(0))
+                    .mb(px(8))
+                    .font_size(FontSize::Caption)
+                    .font_weight_v700()
+                    .color(Color::MainText),
+            )
+            .style(".site-footer ul", s().list_style_type_none().m(px(0)).p(px(0)))
+            .style(".site-footer li", s().mb(px(4)))
+            .style(".site-footer p", s().m(px(0)).mb(px(4)))
+            .style(".site-footer a", s().color(Color::MutedText))
+            .style(".site-footer a:hover", s().color(Color::Primary))
+            .style(".site-footer", s().media("@media print").display_none())
+            .activate_styles()
+    });
+}
@@ -8,7 +8,7 @@ use seed_style::{pc, px};
 
 pub fn view(_model: &Model) -> Node<Msg> {
     div![
-        md![r#"
+        prose![r#"
 # Getting Started
 
 Seed Style is a comprehensive styling, layout and theming solution for Seed apps.  
@@ -83,12 +83,12 @@ h) A plain `'static &str`.   `s().font_size("12px")`
             div![s().width(px(30)).flex_none().bg_color(Color::MutedPrimary)],
             div![
                 s().p(px(12)),
-                md![r#"
+                prose![r#"
 In fact you can use any argument that implements the `UpdateStyle` trait, which all the above do. Advanced 
 users can therefore extend Seed Style by implementing `UpdateStyle` to process arbitrary input."#]
             ]
         ],
-        md![r#"
+        prose![r#"
 
 ## Basic themes
 
@@ -8,7 +8,7 @@ use seed_style::*;
 
 pub fn view(_model: &Model) -> Node<Msg> {
     div![
-        md![r#"
+        prose![r#"
 ## Introduction
 
 This page will show you how to get a Seed application that is ready for Seed Style setup. It will also take you through 
@@ -48,7 +48,7 @@ Currently the main green button is defined like this:
                 "Click Me",
             ]
         ],
-        md![r#"
+        prose![r#"
 ```
 button![
     s().background_color(Color::MutedPrimary)
@@ -114,7 +114,7 @@ Now recompile with `cargo make start` and refresh `http://localhost:8000`:
                 "Click Me",
             ]
         ],
-        md![r#"
+        prose![r#"
 
 Great, the button is now red, however hovering and clicking still uses the green theme shades!  These shades 
 are governed by the `:hover` and `:active` pseudo selectors.  `hover()` applied to a style tags that entire block
@@ -1,5 +1,7 @@
 use crate::app_styling::theme::*;
-use crate::{Model, Msg};
+use crate::commands::{self, Command, CommandGroup};
+use crate::shortcuts::{self, Shortcut};
+use crate::{breadcrumbs, device_preview, nav, search, Model, Msg};
 use seed::{prelude::*, *};
 use seed_hooks::*;
 use seed_style::*;
@@ -10,43 +12,147 @@ enum Area {
     Home,
     Theme,
     HeaderOptions,
+    Search,
+    // the space between the home link and the search, which shows the breadcrumbs
     Empty,
 }
 
-impl LayoutArea for Area {
-    fn is_empty(&self) -> bool {
-        *self == Area::Empty
+impl LayoutArea for Area {}
+#[atom]
+pub fn using_dark_theme() -> bool {
+    false
+}
+
+// Switches between the light and dark themes.
+pub fn toggle_theme() {
+    using_dark_theme().update(|dark| {
+        if *dark {
+            change_theme_with_name("dark_theme", my_theme())
+        } else {
+            change_theme_with_name("light_theme", dark_theme());
+        }
+        *dark = !*dark;
+    });
+}
+
+pub fn register_commands() {
+    commands::register(Command::new(CommandGroup::Action, "Toggle theme", || {
+        toggle_theme();
+        Msg::NoOp
+    }));
+}
+
+pub const HEADER_HEIGHT: i32 = 64;
+
+// The height of the header while it is shown and 0 while it is hidden, for elements that sit
+// below the fixed header.
+pub const HEADER_OFFSET: &str = "var(--header-offset)";
+
+// Auto-hiding header
+// ------------------
+//
+// At `ExtraSmall` the header is fixed to the top of the window. It slides away while scrolling down
+// and comes back when scrolling up, so it does not permanently take 64px of a small screen.
+//
+// The direction only counts once the window has scrolled `SCROLL_THRESHOLD` pixels in it, so small
+// movements and the bounce of touch scrolling do not make the header jitter. Near the top of the page
+// the header is always shown.
+//
+// Hiding just sets `data-header-hidden` on the body, the `--header-offset` variable and the header's
+// transform follow from the global styles.
+
+const SCROLL_THRESHOLD: f64 = 24.;
+
+thread_local! {
+    // The last scroll position and how far the window has moved in the current direction.
+    static SCROLL: std::cell::Cell<(f64, f64)> = std::cell::Cell::new((0., 0.));
+}
+
+pub fn on_window_scroll() {
+    let y = window().page_y_offset().unwrap_or(0.);
+    let (last_y, travelled) = SCROLL.with(|scroll| scroll.get());
+    let delta = y - last_y;
+
+    // a change of direction starts counting again
+    let travelled = if delta.signum() == travelled.signum() {
+        travelled + delta
+    } else {
+        delta
+    };
+    SCROLL.with(|scroll| scroll.set((y, travelled)));
+
+    let body = match document().body() {
+        Some(body) => body,
+        None => return,
+    };
+    // The header is only fixed at the breakpoint that shows the drawer toggle. It also stays while
+    // it is in use, e.g. while typing a search.
+    let header_in_use = document()
+        .active_element()
+        .map_or(false, |active| active.id() == search::SEARCH_INPUT_ID);
+    let hide = if get_html_element_by_id(nav::TOGGLE_ID).is_none()
+        || header_in_use
+        || y <= f64::from(HEADER_HEIGHT)
+    {
+        false
+    } else if travelled > SCROLL_THRESHOLD {
+        true
+    } else if travelled < -SCROLL_THRESHOLD {
+        false
+    } else {
+        return;
+    };
+
+    if hide != body.has_attribute("data-header-hidden") {
+        let _ = if hide {
+            body.set_attribute("data-header-hidden", "")
+        } else {
+            body.remove_attribute("data-header-hidden")
+        };
     }
 }
+
+pub fn register_shortcuts() {
+    shortcuts::register(Shortcut::new(&["t"], "Toggle theme", || {
+        toggle_theme();
+        Msg::NoOp
+    }));
+}
+
+// The header's layouts follow the frame when it is rendered in a device preview.
 pub fn view(model: &Model) -> Node<Msg> {
     use Area::*;
     use Breakpoint::*;
-    Composition::with_layouts(&[
+    device_preview::with_layouts(vec![
         (
             Small,
-            Layout::areas(&[&[Home, Empty, Theme]])
+            Layout::areas(&[&[Home, Empty, Search, Theme]])
                 .style(
-                    s().h(px(64))
-                        .grid_template_columns("auto 1fr auto")
+                    s().h(px(HEADER_HEIGHT))
+                        .grid_template_columns("auto 1fr minmax(0px,320px) auto")
                         .align_items_center()
                         .padding_x(3),
                 )
                 .area_style(Home, s().justify_self_left())
+                .area_style(Empty, s().min_width(px(0)))
+                .area_style(Search, s().mr(px(12)))
                 .area_style(Theme, s().justify_self_right()),
         ),
         (
             ExtraSmall,
-            Layout::areas(&[&[Home, Empty, HeaderOptions, Theme]])
+            Layout::areas(&[&[Home, Search, HeaderOptions, Theme]])
                 .style(
-                    s().h(px(64))
+                    s().h(px(HEADER_HEIGHT))
                         .grid_template_columns("auto 1fr auto auto")
                         .align_items_center()
                         .padding_x(3),
                 )
                 .area_style(Home, s().justify_self_left())
+                .area_style(Search, s().px(px(12)))
                 .area_style(HeaderOptions, s().justify_self_right()),
         ),
     ])
+    .add_style(s().media("@media print").display_none())
     .set_content(Home, |_model: &Model| {
         div![
             a![
@@ -59,6 +165,8 @@ pub fn view(model: &Model) -> Node<Msg> {
         ],
         ]
     })
+    .set_content(Empty, breadcrumbs::view)
+    .set_content(Search, |_| search::view())
     .set_content(HeaderOptions, |m: &Model| {
         div![button![
             s().px(4)
@@ -70,12 +178,18 @@ pub fn view(model: &Model) -> Node<Msg> {
                 .b_width(0)
                 .b_color(Color::Primary)
                 .b_style_solid(),
+            attrs! {
+                At::Id => nav::TOGGLE_ID,
+                At::Type => "button",
+                At::from("aria-label") => "Navigation menu",
+                At::from("aria-controls") => nav::DRAWER_ID,
+                At::from("aria-expanded") => m.show_drawer.get().to_string(),
+            },
             "☰",
             m.show_drawer.on_click(|v| *v = !*v)
         ]]
     })
     .set_content(Theme, |_m| {
-        let using_dark_theme = use_state(|| false);
         div![button![
             s().px(4)
                 .py(2)
@@ -86,18 +200,14 @@ pub fn view(model: &Model) -> Node<Msg> {
                 .b_color(Color::Primary)
                 .cursor_pointer(),
             s().hover().bg_color(Color::Primary),                
-            if using_dark_theme.get() {
+            if using_dark_theme().get() {
                 "Dark Theme"
             } else {
                 "Light Theme"
             },
-            using_dark_theme.on_click(|t| {
-                if *t {
-                    change_theme_with_name("dark_theme", my_theme())
-                } else {
-                    change_theme_with_name("light_theme", dark_theme());
-                }
-                *t = !*t;
+            mouse_ev(Ev::Click, |_| {
+                toggle_theme();
+                Msg::NoOp
             })
         ]]
     })
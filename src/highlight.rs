@@ -0,0 +1,129 @@
+use crate::app_styling::theme::*;
+use crate::code_blocks;
+use seed::prelude::*;
+use seed_hooks::*;
+use seed_style::*;
+
+mod tokenizer;
+pub use tokenizer::{tokenize, Language, Token, TokenKind};
+
+// Syntax highlighting for code blocks
+// -----------------------------------
+//
+// Every `pre > code` block in a rendered page has its text replaced with one `span` per token.
+// This happens on the virtual dom before it is patched, therefore it works for `md!` code fences
+// as well as `pre![code![...]]` written in views and it needs no javascript.
+//
+// The language is taken from the code fence (```` ```rust ```` renders as `class="language-rust"`),
+// untagged blocks are left as plain text unless they are clearly Rust, toml or shell commands.
+//
+// Token colours are `Color` aliases so highlighting follows the active light/dark theme.
+//
+// Highlighted blocks are then decorated with a toolbar (see `code_blocks.rs`).
+
+pub fn highlight_code_blocks<Ms: 'static>(node: &mut Node<Ms>) {
+    if let Node::Element(el) = node {
+        if el.tag == Tag::Pre {
+            let highlighted = el.children.iter_mut().find_map(|child| match child {
+                Node::Element(code) if code.tag == Tag::Code => highlight_code(code),
+                _ => None,
+            });
+            if let Some((language, src)) = highlighted {
+                code_blocks::decorate_code_block(el, language, src);
+            }
+        } else {
+            for child in el.children.iter_mut() {
+                highlight_code_blocks(child);
+            }
+        }
+    }
+}
+
+// Returns the language and source of the block if it was highlighted.
+fn highlight_code<Ms>(code: &mut El<Ms>) -> Option<(Language, String)> {
+    // Only blocks of plain text are highlighted, this also skips blocks that already have been.
+    if code.children.is_empty() || !code.children.iter().all(Node::is_text) {
+        return None;
+    }
+
+    let src = code.get_text();
+    let language = fence_language(code).unwrap_or_else(|| Language::guess(&src));
+
+    code.children = tokenize(language, &src)
+        .into_iter()
+        .map(|token| match token_class(token.kind) {
+            Some(class) => {
+                let mut span = El::empty(Tag::Span);
+                span.add_class(class);
+                span.add_child(Node::new_text(token.text.to_string()));
+                Node::Element(span)
+            }
+            None => Node::new_text(token.text.to_string()),
+        })
+        .collect();
+
+    Some((language, src))
+}
+
+// `Some(language)` if the block came from a fence with a known language tag.
+fn fence_language<Ms>(code: &El<Ms>) -> Option<Language> {
+    match code.attrs.vals.get(&At::Class) {
+        Some(AtValue::Some(classes)) => classes
+            .split_whitespace()
+            .filter(|class| class.starts_with("language-"))
+            .find_map(|class| Language::from_tag(&class["language-".len()..])),
+        _ => None,
+    }
+}
+
+fn token_class(kind: TokenKind) -> Option<&'static str> {
+    use TokenKind::*;
+    match kind {
+        Plain => None,
+        Keyword => Some("hl-keyword"),
+        Type => Some("hl-type"),
+        Macro => Some("hl-macro"),
+        Attribute => Some("hl-attribute"),
+        Lifetime => Some("hl-lifetime"),
+        String => Some("hl-string"),
+        Number => Some("hl-number"),
+        Comment => Some("hl-comment"),
+        Key => Some("hl-key"),
+        Section => Some("hl-section"),
+        Prompt => Some("hl-prompt"),
+        Command => Some("hl-command"),
+        Flag => Some("hl-flag"),
+        Variable => Some("hl-variable"),
+    }
+}
+
+// The colour of each token class.
+pub const TOKEN_COLORS: &[(&str, Color)] = &[
+    (".hl-keyword", Color::CodeKeyword),
+    (".hl-type", Color::CodeType),
+    (".hl-macro", Color::CodeFunction),
+    (".hl-attribute", Color::CodeComment),
+    (".hl-lifetime", Color::CodeType),
+    (".hl-string", Color::CodeString),
+    (".hl-number", Color::CodeNumber),
+    (".hl-comment", Color::CodeComment),
+    (".hl-key", Color::CodeFunction),
+    (".hl-section", Color::CodeKeyword),
+    (".hl-prompt", Color::CodeComment),
+    (".hl-command", Color::CodeFunction),
+    (".hl-flag", Color::CodeNumber),
+    (".hl-variable", Color::CodeType),
+];
+
+#[reaction]
+pub fn highlight_styles() -> () {
+    app_themes().on_update(|| {
+        TOKEN_COLORS
+            .iter()
+            .fold(GlobalStyle::new(), |styles, (selector, color)| {
+                styles.style(selector, s().color(color.clone()))
+            })
+            .style(".hl-comment", s().font_style_italic())
+            .activate_styles()
+    });
+}
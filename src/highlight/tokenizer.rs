@@ -0,0 +1,746 @@
+// Tokenizers for the languages that appear in code blocks across the documentation.
+//
+// These are deliberately simple single pass lexers. They only need to be good enough to
+// colour documentation snippets, therefore they never fail, any input they do not understand
+// is passed through as `Plain` text.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    Toml,
+    Shell,
+    // program output and anything else that is not highlighted
+    Plain,
+}
+
+impl Language {
+    // Language from the info string of a code fence, i.e. ```` ```rust ````.
+    pub fn from_tag(tag: &str) -> Option<Language> {
+        match tag.trim().to_lowercase().as_str() {
+            "rust" | "rs" => Some(Language::Rust),
+            "toml" => Some(Language::Toml),
+            "sh" | "bash" | "shell" | "console" => Some(Language::Shell),
+            "text" | "txt" | "plain" | "output" => Some(Language::Plain),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Rust => "rust",
+            Language::Toml => "toml",
+            Language::Shell => "shell",
+            Language::Plain => "text",
+        }
+    }
+
+    // Best guess at the language of an untagged code block, `Plain` unless it is clearly
+    // shell commands, toml or Rust.
+    pub fn guess(src: &str) -> Language {
+        let lines = src
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with("//") && !line.starts_with('#'))
+            .collect::<Vec<_>>();
+
+        if lines.is_empty() {
+            return Language::Plain;
+        }
+
+        let shell_prefixes = ["> ", "$ ", "cargo ", "rustup ", "git ", "cd ", "wasm-pack "];
+        if lines
+            .iter()
+            .all(|line| shell_prefixes.iter().any(|prefix| line.starts_with(prefix)))
+        {
+            return Language::Shell;
+        }
+
+        if lines.iter().all(|line| is_toml_line(line)) {
+            return Language::Toml;
+        }
+
+        // snippets are often fragments, so most rather than all lines have to look like Rust
+        let rust_lines = lines.iter().filter(|line| is_rust_line(line)).count();
+        if rust_lines * 2 >= lines.len() {
+            return Language::Rust;
+        }
+
+        Language::Plain
+    }
+}
+
+fn is_rust_line(line: &str) -> bool {
+    let keywords = [
+        "fn ", "pub ", "let ", "use ", "impl", "struct ", "enum ", "mod ", "match ", "if ", "for ",
+        "while ", "const ", "static ", "trait ", "type ", "async ", "return", "#[",
+    ];
+    keywords.iter().any(|keyword| line.starts_with(keyword))
+        || line.starts_with(|c| c == '.' || c == '}' || c == ']' || c == ')')
+        || line.ends_with(|c| c == ';' || c == '{' || c == '(' || c == '[' || c == ',')
+        || ["![", "::", "=>", "->", "()."].iter().any(|token| line.contains(token))
+}
+
+fn is_toml_line(line: &str) -> bool {
+    if line.starts_with('[') && line.ends_with(']') {
+        return line
+            .trim_matches(|c| c == '[' || c == ']')
+            .chars()
+            .all(|c| is_bare_key_char(c) || c == '.');
+    }
+    match line.find('=') {
+        Some(idx) => {
+            let key = line[..idx].trim();
+            !key.is_empty() && key.chars().all(|c| is_bare_key_char(c) || c == '.')
+        }
+        None => false,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Plain,
+    Keyword,
+    Type,
+    Macro,
+    Attribute,
+    Lifetime,
+    String,
+    Number,
+    Comment,
+    // toml
+    Key,
+    Section,
+    // shell
+    Prompt,
+    Command,
+    Flag,
+    Variable,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+}
+
+pub fn tokenize(language: Language, src: &str) -> Vec<Token<'_>> {
+    match language {
+        Language::Rust => tokenize_rust(src),
+        Language::Toml => tokenize_toml(src),
+        Language::Shell => tokenize_shell(src),
+        Language::Plain => vec![Token {
+            kind: TokenKind::Plain,
+            text: src,
+        }],
+    }
+}
+
+// A cursor over the source, all positions are byte offsets on char boundaries.
+struct Lexer<'a> {
+    src: &'a str,
+    pos: usize,
+    // (kind, start, end) byte ranges into `src`
+    spans: Vec<(TokenKind, usize, usize)>,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(src: &'a str) -> Lexer<'a> {
+        Lexer {
+            src,
+            pos: 0,
+            spans: vec![],
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn bump_while<F: Fn(char) -> bool>(&mut self, pred: F) {
+        while let Some(c) = self.peek() {
+            if !pred(c) {
+                break;
+            }
+            self.bump();
+        }
+    }
+
+    fn bump_to_end_of_line(&mut self) {
+        self.bump_while(|c| c != '\n');
+    }
+
+    // Consume a quoted string starting at the current quote, honouring backslash escapes.
+    fn bump_quoted(&mut self, quote: char, escapes: bool) {
+        self.bump();
+        while let Some(c) = self.bump() {
+            if escapes && c == '\\' {
+                self.bump();
+            } else if c == quote {
+                break;
+            }
+        }
+    }
+
+    // Adjacent plain tokens are merged so that they render as a single text node.
+    fn push(&mut self, kind: TokenKind, start: usize) {
+        if self.pos == start {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some((TokenKind::Plain, _, end)) if kind == TokenKind::Plain && *end == start => {
+                *end = self.pos
+            }
+            _ => self.spans.push((kind, start, self.pos)),
+        }
+    }
+
+    fn finish(self) -> Vec<Token<'a>> {
+        let src = self.src;
+        self.spans
+            .into_iter()
+            .map(|(kind, start, end)| Token {
+                kind,
+                text: &src[start..end],
+            })
+            .collect()
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.src[..self.pos]
+            .chars()
+            .rev()
+            .take_while(|c| *c != '\n')
+            .all(char::is_whitespace)
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_bare_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+const RUST_PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+    "u128", "usize", "f32", "f64",
+];
+
+fn tokenize_rust(src: &str) -> Vec<Token<'_>> {
+    let mut lx = Lexer::new(src);
+
+    while let Some(c) = lx.peek() {
+        let start = lx.pos;
+        match c {
+            '/' if lx.peek_nth(1) == Some('/') => {
+                lx.bump_to_end_of_line();
+                lx.push(TokenKind::Comment, start);
+            }
+            '/' if lx.peek_nth(1) == Some('*') => {
+                match lx.rest()[2..].find("*/") {
+                    Some(end) => lx.pos += 2 + end + 2,
+                    None => lx.pos = src.len(),
+                }
+                lx.push(TokenKind::Comment, start);
+            }
+            '#' if lx.peek_nth(1) == Some('[')
+                || (lx.peek_nth(1) == Some('!') && lx.peek_nth(2) == Some('[')) =>
+            {
+                let mut depth = 0;
+                while let Some(c) = lx.bump() {
+                    match c {
+                        '[' => depth += 1,
+                        ']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                lx.push(TokenKind::Attribute, start);
+            }
+            '"' => {
+                lx.bump_quoted('"', true);
+                lx.push(TokenKind::String, start);
+            }
+            'r' | 'b' if is_raw_string_start(lx.rest()) => {
+                bump_raw_string(&mut lx);
+                lx.push(TokenKind::String, start);
+            }
+            'b' if lx.peek_nth(1) == Some('"') => {
+                lx.bump();
+                lx.bump_quoted('"', true);
+                lx.push(TokenKind::String, start);
+            }
+            '\'' => {
+                // either a char literal 'a' '\n' or a lifetime 'a
+                if lx.peek_nth(1) == Some('\\') {
+                    lx.bump_quoted('\'', true);
+                    lx.push(TokenKind::String, start);
+                } else if lx.peek_nth(2) == Some('\'') {
+                    lx.bump();
+                    lx.bump();
+                    lx.bump();
+                    lx.push(TokenKind::String, start);
+                } else if lx.peek_nth(1).map_or(false, is_ident_start) {
+                    lx.bump();
+                    lx.bump_while(is_ident_char);
+                    lx.push(TokenKind::Lifetime, start);
+                } else {
+                    lx.bump();
+                    lx.push(TokenKind::Plain, start);
+                }
+            }
+            c if c.is_ascii_digit() => {
+                bump_number(&mut lx);
+                lx.push(TokenKind::Number, start);
+            }
+            c if is_ident_start(c) => {
+                lx.bump_while(is_ident_char);
+                let word = &src[start..lx.pos];
+                if lx.peek() == Some('!') && lx.peek_nth(1) != Some('=') {
+                    lx.bump();
+                    lx.push(TokenKind::Macro, start);
+                } else if RUST_KEYWORDS.contains(&word) {
+                    lx.push(TokenKind::Keyword, start);
+                } else if RUST_PRIMITIVES.contains(&word)
+                    || word.chars().next().map_or(false, char::is_uppercase)
+                {
+                    lx.push(TokenKind::Type, start);
+                } else {
+                    lx.push(TokenKind::Plain, start);
+                }
+            }
+            _ => {
+                lx.bump();
+                lx.push(TokenKind::Plain, start);
+            }
+        }
+    }
+    lx.finish()
+}
+
+// r"..", r#".."#, br".." and so on.
+fn is_raw_string_start(rest: &str) -> bool {
+    let rest = rest.trim_start_matches('b');
+    rest.starts_with('r') && rest[1..].trim_start_matches('#').starts_with('"')
+}
+
+fn bump_raw_string(lx: &mut Lexer) {
+    if lx.peek() == Some('b') {
+        lx.bump();
+    }
+    lx.bump(); // r
+    let mut hashes = 0;
+    while lx.peek() == Some('#') {
+        lx.bump();
+        hashes += 1;
+    }
+    lx.bump(); // opening quote
+    let closing = format!("\"{}", "#".repeat(hashes));
+    match lx.rest().find(&closing) {
+        Some(end) => lx.pos += end + closing.len(),
+        None => lx.pos = lx.src.len(),
+    }
+}
+
+// Integers and floats including `_` separators, hex/binary prefixes and type suffixes.
+fn bump_number(lx: &mut Lexer) {
+    lx.bump_while(|c| c.is_ascii_alphanumeric() || c == '_');
+    // a fractional part, but not a range `0..10` or a method call `1.max(2)`
+    if lx.peek() == Some('.') && lx.peek_nth(1).map_or(false, |c| c.is_ascii_digit()) {
+        lx.bump();
+        lx.bump_while(|c| c.is_ascii_alphanumeric() || c == '_');
+    }
+}
+
+fn tokenize_toml(src: &str) -> Vec<Token<'_>> {
+    let mut lx = Lexer::new(src);
+
+    while let Some(c) = lx.peek() {
+        let start = lx.pos;
+        match c {
+            '#' => {
+                lx.bump_to_end_of_line();
+                lx.push(TokenKind::Comment, start);
+            }
+            // not valid toml, but common in snippets that annotate which file they belong to.
+            '/' if lx.peek_nth(1) == Some('/') && lx.at_line_start() => {
+                lx.bump_to_end_of_line();
+                lx.push(TokenKind::Comment, start);
+            }
+            '[' if lx.at_line_start() => {
+                lx.bump_while(|c| c != ']' && c != '\n');
+                lx.bump_while(|c| c == ']');
+                lx.push(TokenKind::Section, start);
+            }
+            '"' | '\'' => {
+                let triple: String = std::iter::repeat(c).take(3).collect();
+                if lx.rest().starts_with(&triple) {
+                    lx.pos += 3;
+                    match lx.rest().find(&triple) {
+                        Some(end) => lx.pos += end + 3,
+                        None => lx.pos = src.len(),
+                    }
+                } else {
+                    lx.bump_quoted(c, c == '"');
+                }
+                lx.push(TokenKind::String, start);
+            }
+            c if is_bare_key_char(c) || c == '+' => {
+                lx.bump_while(|c| is_bare_key_char(c) || c == '.' || c == ':' || c == '+');
+                let word = &src[start..lx.pos];
+                let followed_by_equals = lx.rest().trim_start_matches(' ').starts_with('=');
+                if followed_by_equals {
+                    lx.push(TokenKind::Key, start);
+                } else if word == "true" || word == "false" {
+                    lx.push(TokenKind::Keyword, start);
+                } else if word
+                    .trim_start_matches(|c| c == '+' || c == '-')
+                    .starts_with(|c: char| c.is_ascii_digit())
+                {
+                    lx.push(TokenKind::Number, start);
+                } else {
+                    lx.push(TokenKind::Plain, start);
+                }
+            }
+            _ => {
+                lx.bump();
+                lx.push(TokenKind::Plain, start);
+            }
+        }
+    }
+    lx.finish()
+}
+
+fn tokenize_shell(src: &str) -> Vec<Token<'_>> {
+    let mut lx = Lexer::new(src);
+    // true when the next word is in command position
+    let mut expect_command = true;
+
+    while let Some(c) = lx.peek() {
+        let start = lx.pos;
+        match c {
+            '\n' => {
+                lx.bump();
+                lx.push(TokenKind::Plain, start);
+                expect_command = true;
+            }
+            c if c.is_whitespace() => {
+                lx.bump_while(|c| c.is_whitespace() && c != '\n');
+                lx.push(TokenKind::Plain, start);
+            }
+            '>' | '$'
+                if lx.at_line_start() && lx.peek_nth(1).map_or(true, char::is_whitespace) =>
+            {
+                lx.bump();
+                lx.push(TokenKind::Prompt, start);
+            }
+            '#' => {
+                lx.bump_to_end_of_line();
+                lx.push(TokenKind::Comment, start);
+            }
+            '"' | '\'' => {
+                lx.bump_quoted(c, c == '"');
+                lx.push(TokenKind::String, start);
+                expect_command = false;
+            }
+            '$' => {
+                lx.bump();
+                if lx.peek() == Some('{') {
+                    lx.bump_while(|c| c != '}');
+                    lx.bump();
+                } else {
+                    lx.bump_while(|c| is_ident_char(c));
+                }
+                lx.push(TokenKind::Variable, start);
+                expect_command = false;
+            }
+            ';' | '|' | '&' => {
+                lx.bump_while(|c| c == ';' || c == '|' || c == '&');
+                lx.push(TokenKind::Plain, start);
+                expect_command = true;
+            }
+            _ => {
+                lx.bump_while(|c| !c.is_whitespace() && !";|&\"'".contains(c));
+                let kind = if expect_command {
+                    TokenKind::Command
+                } else if c == '-' {
+                    TokenKind::Flag
+                } else {
+                    TokenKind::Plain
+                };
+                lx.push(kind, start);
+                expect_command = false;
+            }
+        }
+    }
+    lx.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TokenKind::*;
+
+    fn kinds(language: Language, src: &str) -> Vec<(TokenKind, &str)> {
+        tokenize(language, src)
+            .into_iter()
+            .map(|t| (t.kind, t.text))
+            .collect()
+    }
+
+    fn round_trips(language: Language, src: &str) {
+        let joined: std::string::String = tokenize(language, src).iter().map(|t| t.text).collect();
+        assert_eq!(joined, src);
+    }
+
+    #[test]
+    fn rust_keywords_types_and_macros() {
+        assert_eq!(
+            kinds(Language::Rust, "let count = use_state(|| 0);"),
+            vec![
+                (Keyword, "let"),
+                (Plain, " count = use_state(|| "),
+                (Number, "0"),
+                (Plain, ");"),
+            ]
+        );
+        assert_eq!(
+            kinds(Language::Rust, "fn view() -> Node<Msg> { div![] }"),
+            vec![
+                (Keyword, "fn"),
+                (Plain, " view() -> "),
+                (Type, "Node"),
+                (Plain, "<"),
+                (Type, "Msg"),
+                (Plain, "> { "),
+                (Macro, "div!"),
+                (Plain, "[] }"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rust_not_equals_is_not_a_macro() {
+        assert_eq!(
+            kinds(Language::Rust, "a != b"),
+            vec![(Plain, "a != b")]
+        );
+    }
+
+    #[test]
+    fn rust_comments_and_attributes() {
+        assert_eq!(
+            kinds(Language::Rust, "#[topo::nested] // root\n/* block */"),
+            vec![
+                (Attribute, "#[topo::nested]"),
+                (Plain, " "),
+                (Comment, "// root"),
+                (Plain, "\n"),
+                (Comment, "/* block */"),
+            ]
+        );
+        assert_eq!(
+            kinds(Language::Rust, "#![feature(track_caller)]"),
+            vec![(Attribute, "#![feature(track_caller)]")]
+        );
+    }
+
+    #[test]
+    fn rust_strings_chars_and_lifetimes() {
+        assert_eq!(
+            kinds(Language::Rust, r#""a \"quoted\" str""#),
+            vec![(String, r#""a \"quoted\" str""#)]
+        );
+        assert_eq!(
+            kinds(Language::Rust, "r#\"raw \"str\"\"# 'a' '\\n' &'static"),
+            vec![
+                (String, "r#\"raw \"str\"\"#"),
+                (Plain, " "),
+                (String, "'a'"),
+                (Plain, " "),
+                (String, "'\\n'"),
+                (Plain, " &"),
+                (Lifetime, "'static"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rust_numbers() {
+        assert_eq!(
+            kinds(Language::Rust, "1_000u32 0.5 0..10"),
+            vec![
+                (Number, "1_000u32"),
+                (Plain, " "),
+                (Number, "0.5"),
+                (Plain, " "),
+                (Number, "0"),
+                (Plain, ".."),
+                (Number, "10"),
+            ]
+        );
+    }
+
+    #[test]
+    fn toml_sections_keys_and_values() {
+        assert_eq!(
+            kinds(
+                Language::Toml,
+                "[dependencies]\nseed = \"0.7.0\" # web\nlto = true\nopt-level = 3"
+            ),
+            vec![
+                (Section, "[dependencies]"),
+                (Plain, "\n"),
+                (Key, "seed"),
+                (Plain, " = "),
+                (String, "\"0.7.0\""),
+                (Plain, " "),
+                (Comment, "# web"),
+                (Plain, "\n"),
+                (Key, "lto"),
+                (Plain, " = "),
+                (Keyword, "true"),
+                (Plain, "\n"),
+                (Key, "opt-level"),
+                (Plain, " = "),
+                (Number, "3"),
+            ]
+        );
+    }
+
+    #[test]
+    fn toml_inline_tables() {
+        assert_eq!(
+            kinds(Language::Toml, "seed_style = { package = \"seed_style_preview\" }"),
+            vec![
+                (Key, "seed_style"),
+                (Plain, " = { "),
+                (Key, "package"),
+                (Plain, " = "),
+                (String, "\"seed_style_preview\""),
+                (Plain, " }"),
+            ]
+        );
+    }
+
+    #[test]
+    fn shell_prompts_commands_and_flags() {
+        assert_eq!(
+            kinds(Language::Shell, "> cargo make serve\nrustup target add wasm32-unknown-unknown"),
+            vec![
+                (Prompt, ">"),
+                (Plain, " "),
+                (Command, "cargo"),
+                (Plain, " make serve\n"),
+                (Command, "rustup"),
+                (Plain, " target add wasm32-unknown-unknown"),
+            ]
+        );
+        assert_eq!(
+            kinds(Language::Shell, "cargo make build; cargo test --release # all"),
+            vec![
+                (Command, "cargo"),
+                (Plain, " make build; "),
+                (Command, "cargo"),
+                (Plain, " test "),
+                (Flag, "--release"),
+                (Plain, " "),
+                (Comment, "# all"),
+            ]
+        );
+    }
+
+    #[test]
+    fn shell_strings_and_variables() {
+        assert_eq!(
+            kinds(Language::Shell, "echo \"$HOME\" $PATH ${CARGO}"),
+            vec![
+                (Command, "echo"),
+                (Plain, " "),
+                (String, "\"$HOME\""),
+                (Plain, " "),
+                (Variable, "$PATH"),
+                (Plain, " "),
+                (Variable, "${CARGO}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokens_cover_the_whole_source() {
+        round_trips(Language::Rust, "fn ünïcode() { let s = \"→\"; 'x' }\n/* unterminated");
+        round_trips(Language::Rust, "r##\"unterminated");
+        round_trips(Language::Toml, "a = '''\nmulti\n''' [x\n");
+        round_trips(Language::Shell, "echo 'unterminated");
+    }
+
+    #[test]
+    fn language_from_fence_tags_and_guesses() {
+        assert_eq!(Language::from_tag("rust"), Some(Language::Rust));
+        assert_eq!(Language::from_tag("Bash"), Some(Language::Shell));
+        assert_eq!(Language::from_tag("toml"), Some(Language::Toml));
+        assert_eq!(Language::from_tag("haskell"), None);
+
+        assert_eq!(
+            Language::guess("> git clone x\n> cd x\n> cargo make serve"),
+            Language::Shell
+        );
+        assert_eq!(Language::guess("cargo install cargo-make"), Language::Shell);
+        assert_eq!(
+            Language::guess("// In Cargo.toml...\n\nseed_hooks = \"0.1.4\""),
+            Language::Toml
+        );
+        assert_eq!(Language::guess("let a = 1;"), Language::Rust);
+        assert_eq!(Language::guess("div![\n    s().color(\"red\")\n]"), Language::Rust);
+        assert_eq!(Language::from_tag("text"), Some(Language::Plain));
+    }
+
+    #[test]
+    fn untagged_blocks_default_to_plain_text() {
+        assert_eq!(Language::guess(""), Language::Plain);
+        assert_eq!(Language::guess("Smallest Log: 3\nLargest Log: 12"), Language::Plain);
+        assert_eq!(
+            Language::guess("Compiling seed v0.7.0\nFinished dev [unoptimized + debuginfo] target(s) in 9.1s"),
+            Language::Plain
+        );
+        assert_eq!(Language::guess("http://localhost:8000"), Language::Plain);
+    }
+
+    #[test]
+    fn plain_text_is_one_plain_token() {
+        let tokens = tokenize(Language::Plain, "fn looks_like_rust() {}");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].kind, TokenKind::Plain);
+        assert_eq!(tokens[0].text, "fn looks_like_rust() {}");
+    }
+}
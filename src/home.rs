@@ -1,3 +1,4 @@
+use crate::layouts::{self, HeroPlacement};
 use crate::{Model, Msg};
 use seed::{prelude::*, *};
 use seed_style::{pc, px,vh};
@@ -74,30 +75,16 @@ enum HeroHeaderArea {
     
     Title,
     Subtitle,
-    Empty,
 }
 
-impl LayoutArea for HeroHeaderArea {
-    fn is_empty(&self) -> bool {
-        self == &HeroHeaderArea::Empty
-    }
-}
+impl LayoutArea for HeroHeaderArea {}
 
 fn hero_header_ss(model: &Model) -> Node<Msg> {
     use HeroHeaderArea::*;
     div![
         s().h(px(300)).background_image("linear-gradient(darkgreen, #aaf006)")
         ,
-        Composition::with_layout(
-            Layout::areas(&[
-                &[Empty, Empty, Empty],
-                &[Empty, Title, Empty],
-            &[Empty, Subtitle, Empty],
-            ])
-            .style(s().grid_template_columns("minmax(0,1fr) auto minmax(0,1fr)"))
-            .area_style(Title, s().align_self_flex_end())
-            .area_style(Subtitle, s().align_self_flex_start())
-        )
+        Composition::with_layouts(&layouts::hero(Title, Subtitle, HeroPlacement::LowerThird))
         .set_content(Title, |_| div![
             s().color(seed_colors::Base::White)
                 .font_weight_v900()
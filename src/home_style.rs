@@ -1,3 +1,4 @@
+use crate::layouts::{self, HeroPlacement};
 use crate::{Model, Msg};
 use seed::{prelude::*, *};
 use seed_style::{pc, px,vh};
@@ -104,7 +105,7 @@ fn main_content(model: &Model) -> Node<Msg> {
                     "Seed Rocks!"
                 ]
             ],
-            md![r#"
+            prose![r#"
 
 ```
 button![
@@ -125,8 +126,8 @@ button![
     "Seed Rocks!"
 ]
 ```
-"#],h2!["Getting Started"],
-md![r#"The best way to get started is to download the Seed Style quickstart,
+"#],prose!["## Getting Started"],
+prose![r#"The best way to get started is to download the Seed Style quickstart,
  this targets current Seed master and includes `seed_style_preview`
  
  ```
@@ -135,18 +136,9 @@ md![r#"The best way to get started is to download the Seed Style quickstart,
  cargo make start
 ```
  "#]
- ,h2!["Features"],
-            Composition::with_layout(
-                
-                Layout::grid(
-                    s().grid_template_columns("repeat(auto-fit, minmax(300px, 1fr))")
-                        .grid_auto_flow_row()
-                        .justify_items_center()
-                        .grid_template_rows("auto 1fr")
-                        .grid_gap(px(16))
-                        .w(pc(100.)),
-                )
-            )
+ ,prose!["## Features"],
+            Composition::with_layout(layouts::card_grid("300px"))
+            .add_style(s().justify_items_center().grid_template_rows("auto 1fr"))
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "Use styles scoped to individual components."])
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "Optional typing, lets you use traditional CSS or fully typed properties and values."])
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "Fully integrated themes let you swap styles at the press of a button."])
@@ -155,7 +147,7 @@ md![r#"The best way to get started is to download the Seed Style quickstart,
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "Atomic in nature, styles can be freely composed in sophisticated ways"])
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "Integrated full grid layout system, layouts and compositions are first class citizens"])
             .render(model),
-            h2!["Examples"],
+            prose!["## Examples"],
             div![s().display_flex().flex_direction_row(),
                 div![
                     s().bg_color(Color::Primary).w(px(80)).flex_none().h(px(80)).m(px(12)).mt(px(0)).radius(pc(80)),
@@ -252,14 +244,9 @@ enum HeroHeaderArea {
     
     Title,
     Subtitle,
-    Empty,
 }
 
-impl LayoutArea for HeroHeaderArea {
-    fn is_empty(&self) -> bool {
-        self == &HeroHeaderArea::Empty
-    }
-}
+impl LayoutArea for HeroHeaderArea {}
 
 fn hero_header(model: &Model) -> Node<Msg> {
     use HeroHeaderArea::*;
@@ -268,16 +255,7 @@ fn hero_header(model: &Model) -> Node<Msg> {
         a![attrs![At::Name=>"style"]],
         s().h(px(300)).background_image("linear-gradient(red, #f0aa06)")
         ,
-        Composition::with_layout(
-            Layout::areas(&[
-                &[Empty, Empty, Empty],
-                &[Empty, Title, Empty],
-            &[Empty, Subtitle, Empty],
-            ])
-            .style(s().grid_template_columns("minmax(0,1fr) auto minmax(0,1fr)"))
-            .area_style(Title, s().align_self_flex_end())
-            .area_style(Subtitle, s().align_self_flex_start())
-        )
+        Composition::with_layouts(&layouts::hero(Title, Subtitle, HeroPlacement::LowerThird))
         
         .set_content(Title, |_|
             div![
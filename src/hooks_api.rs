@@ -354,9 +354,8 @@ pub fn sec_view<Ms: 'static>(args: SectionArgs, _root: Node<Ms>, _children: Vec<
     let mut c = code_example;
     let (title,sig,modal_content,code_example) = (args.title, args.sig, args.modal_content, args.code_example);
     div![
-        h3![
-            title
-        ],
+        // only the title is prose, the example button and modal keep their own styles
+        div![class!["prose"], h3![title]],
         pre![code![sig]],
         description.clone(),
 
@@ -436,12 +435,12 @@ fn if_example() -> Node<Msg> {
 
 pub fn view(_model : &Model) -> Node<Msg>{
     
-    render_centred_article(_model, |_| {
+    render_centred_article_with_toc(_model, |_| {
         let modal_content = use_state(||None);
         
     div![modal(modal_content),
-    h1!["Seed Hooks Api Notes"],
-    md![r#"
+    prose!["# Seed Hooks Api Notes"],
+    prose![r#"
 There are several functions, methods and objects that contribute to the functionality of seed hooks.
 Some of the most important are described here.
 
@@ -459,7 +458,7 @@ to some hook patterns such as `after_render` and `do_once`.
             modal_content = modal_content,
             code_example = Some(use_state_example),
             description![
-                md![r#"
+                prose![r#"
 `use_state` is the standard state function for storing of a local **state variable** for a component. 
 It returns a `StateAccess` **state accessor** which is responsible for all getting, setting and updating of the underlying value.
 
@@ -491,7 +490,7 @@ fn my_button() -> Node<Msg> {
             code_example = Some(new_state_example),
             sig ="fn new_state<T: 'static, F: FnOnce() -> T>(data_fn: F) -> StateAccess<T>".to_string(),
             description![
-                md![  r#"This function is identical to `use_state` with the exception that every time the function is executed it creates a new 
+                prose![  r#"This function is identical to `use_state` with the exception that every time the function is executed it creates a new 
 topological context. The closure runs on every execution.
 
 The use-case for this is to allow creation of state variables and associated accessors in an event callback.
@@ -566,7 +565,7 @@ fn new_state_example() -> Node<Msg> {
             code_example = Some(if_example),
             sig ="fn after_render<F: Fn(f64) -> () + 'static>(func: F)".to_string(),
             description![
-                md![  r#"`after_render()` executes the closure supplied after the next render. The execution runs asynchronously,
+                prose![  r#"`after_render()` executes the closure supplied after the next render. The execution runs asynchronously,
 specifically after the DOM tree has been created, diffed, and after the view has been painted to the window.
 Often this is combined with `do_once()` which schedules an closure to be executed only once after the next page render.  
 
@@ -614,7 +613,7 @@ fn if_example() -> Node<Msg> {
 
         ]
         ,
-md![
+prose![
 r#"
 ## StateAccess<T>
 
@@ -647,7 +646,7 @@ collection of state accessors to manage complex tree structures.
             code_example = Some(my_input),
             sig ="fn get(&self) -> T // T must be Clone + 'static".to_string(),
             description![
-                md![  r#"This method returns a clone of the stored data, therefore in order for it to be used `T` must of course implement `Clone`.
+                prose![  r#"This method returns a clone of the stored data, therefore in order for it to be used `T` must of course implement `Clone`.
 Although all accesses will therefore cause an allocation due to the clone, this is the most direct way in which to access the stored data.
 Care should be taken in understanding that the clone may be stale if this value is used in a callback.
 
@@ -684,7 +683,7 @@ fn my_input() -> Node<Msg> {
             code_example = Some(my_non_clone_input),
             sig ="fn get_with<F: FnOnce(&T) -> R, R>(self, func: F) -> R".to_string(),
             description![
-                md![  r#"This method provides read access to a stored store variable via a closure.
+                prose![  r#"This method provides read access to a stored store variable via a closure.
 This method is primarily used to read non-`Clone` values or where cloning is seen as expensive.
 
 The typical pattern is to return a representation of the data stored from the `get_with()` closure. 
@@ -726,7 +725,7 @@ fn my_non_clone_input() -> Node<Msg> {
             code_example = Some(set_list),
             sig ="fn set(self, value: T)".to_string(),
             description![
-                md![  r#"This method simply updates the stored value. `set()` is generally called in an `EventHandler` callback.
+                prose![  r#"This method simply updates the stored value. `set()` is generally called in an `EventHandler` callback.
 If the updated value depends on the current value it is generally better to use `update()` rather than `set()`
 
 This example uses `set()` to set the value based on a clicked item in a list."#
@@ -758,7 +757,7 @@ fn set_list() -> Node<Msg> {
             code_example = Some(update_example),
             sig ="fn update<F: FnOnce(&mut T) -> ()>(self, func: F)".to_string(),
             description![
-md![  r#"
+prose![  r#"
 This method simply updates the stored value by providing mutable access within a closure.
 This is the prefered method if updating a value in place, particularly if the change depends on the existing value.
 
@@ -799,7 +798,7 @@ fn update_example() -> Node<Msg> {
 
         ],
 
-        md![r#"
+        prose![r#"
 ## Developer Experience
 
 Seed hooks provide a number of functions to simplify working with hooks.
@@ -812,7 +811,7 @@ Seed hooks provide a number of functions to simplify working with hooks.
             sig ="fn bind<Ms: Default, T: 'static + std::str::FromStr + std::fmt::Display>( attr: At,
                 val: StateAccess<T>,) -> (seed::virtual_dom::attrs::Attrs, seed::EventHandler<Ms>)".to_string(),
             description![
-md![  r#"
+prose![  r#"
 it is a common requirement that the value of element attributes such as an input's 
 value attribute is linked to some value. `bind()` provides a shortcut to link an attribute to a value.
 You simplfy specify the attribute and state accessor to bind.  Currently limited to updating on `Input` events, 
@@ -872,7 +871,7 @@ fn number_without_bind() -> Node<Msg> {
         ],
 
         ],
-        md![
+        prose![
            r#"
 ## Glossary
 
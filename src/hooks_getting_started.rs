@@ -2,71 +2,13 @@ use crate::app_styling::theme::*;
 
 use crate::{Model, Msg};
 use seed::{prelude::*, *};
-use wasm_bindgen::JsCast;
 use seed_style::*;
 use seed_style::{pc, px};
 use seed_hooks::*;
 
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = Prism)]
-    fn highlightElement(el: web_sys::HtmlElement);
-    #[wasm_bindgen(js_namespace = Prism)]
-    fn highlightAllUnder(el: web_sys::HtmlElement);
-}
-
-
-#[topo::nested]
-fn section_desc<T: Into<String>>(href_name: T, title: T, description: T) -> Vec<Node<Msg>> {
-
-    
-
-    let title = md!(&title.into());
-    let description = md!(&description.into());
-
-    let desc_el = use_state(ElRef::<web_sys::HtmlElement>::default);
-
-
-    do_once(|| 
-        after_render(move |_| {
-            if let Some(desc_el) = desc_el.get().get() {
-                let code_children = desc_el.get_elements_by_tag_name("h3");
-
-                for idx in 0..code_children.length() {
-                    let code_el = code_children.item(idx).unwrap();
-                    code_el.set_class_name("text-xl py-3 pt-4");
-                }
-
-                let code_children = desc_el.get_elements_by_tag_name("code");
-
-                for idx in 0..code_children.length() {
-                    let code_el = code_children.item(idx).unwrap();
-                    code_el.set_class_name("language-rust");
-                    highlightElement(code_el.dyn_into::<web_sys::HtmlElement>().unwrap());
-                } 
-            }   
-        })
-    ).reset_on_unmount();
-
-    nodes![
-        h2![
-            s().m(px(3)).font_size(px(24)),
-            a![attrs![At::Name=>href_name.into()], title]
-        ],
-        div![
-            el_ref(&desc_el.get()),
-            s().margin_top(px(3)),
-            description
-        ],
-    ]
-}
-
-
-
-
 pub fn view(_model: &Model) -> Node<Msg> {
     div![
-        md![r#"
+        prose![r#"
 # Getting Started
 
 Seed Hooks is a comprehensive component and global reactive state solution for Seed apps. With Seed Hooks you can create customisable, re-usable and interactive components.
@@ -275,7 +217,7 @@ Styled example:
     
 }
 ,
-md![r###" 
+prose![r###" 
 
 ## Creating a simple form with reactive global state
 
@@ -1,3 +1,4 @@
+use crate::layouts::{self, HeroPlacement};
 use crate::{Model, Msg};
 use seed::{prelude::*, *};
 use seed_style::{pc, px,vh};
@@ -70,14 +71,9 @@ enum HeroHeaderArea {
     
     Title,
     Subtitle,
-    Empty,
 }
 
-impl LayoutArea for HeroHeaderArea {
-    fn is_empty(&self) -> bool {
-        self == &HeroHeaderArea::Empty
-    }
-}
+impl LayoutArea for HeroHeaderArea {}
 
 fn main_content_hooks(model: &Model) -> Node<Msg> {
     Composition::with_layout(
@@ -104,7 +100,7 @@ fn main_content_hooks(model: &Model) -> Node<Msg> {
                     ]
         }              
             ],
-            md![r#"
+            prose![r#"
 
 ### Local State
 
@@ -133,8 +129,8 @@ fancy_button![
 ]
 ```
 
-"#],h2!["Getting Started"],
-md![r#"The best way to get started is to download the Seed Hooks quickstart,
+"#],prose!["## Getting Started"],
+prose![r#"The best way to get started is to download the Seed Hooks quickstart,
  this targets current Seed master and includes `seed_hooks`
  
  ```
@@ -143,18 +139,9 @@ md![r#"The best way to get started is to download the Seed Hooks quickstart,
  cargo make start
 ```
  "#]
- ,h2!["Features"],
-            Composition::with_layout(
-                
-                Layout::grid(
-                    s().grid_template_columns("repeat(auto-fit, minmax(300px, 1fr))")
-                        .grid_auto_flow_row()
-                        .justify_items_center()
-                        .grid_template_rows("auto 1fr")
-                        .grid_gap(px(16))
-                        .w(pc(100.)),
-                )
-            )
+ ,prose!["## Features"],
+            Composition::with_layout(layouts::card_grid("300px"))
+            .add_style(s().justify_items_center().grid_template_rows("auto 1fr"))
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "Use per state component."])
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "Easily cache and memoize complex values"])
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "Trigger effects a single time, for instance on render"])
@@ -162,7 +149,7 @@ md![r#"The best way to get started is to download the Seed Hooks quickstart,
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "easily update state from EventHandlers"])
             .add_child(|_| div![s().w(px(300)).font_weight_v700(), "Atomic in nature, rich interactive components can be build from self contained building blocks"])
             .render(model),
-            h2!["Guides and Examples"],
+            prose!["## Guides and Examples"],
             div![s().display_flex().flex_direction_row(),
                 div![
                     s().bg_color(Color::Primary).w(px(80)).flex_none().h(px(80)).m(px(12)).mt(px(0)).radius(pc(80)),
@@ -207,16 +194,7 @@ fn hero_header_hooks(model: &Model) -> Node<Msg> {
         s().h(px(300)).background_image("linear-gradient(purple, royalblue)")
         ,
         a![attrs![At::Name=>"hooks"]],
-        Composition::with_layout(
-            Layout::areas(&[
-                &[Empty, Empty, Empty],
-                &[Empty, Title, Empty],
-            &[Empty, Subtitle, Empty],
-            ])
-            .style(s().grid_template_columns("minmax(0,1fr) auto minmax(0,1fr)"))
-            .area_style(Title, s().align_self_flex_end())
-            .area_style(Subtitle, s().align_self_flex_start())
-        )
+        Composition::with_layouts(&layouts::hero(Title, Subtitle, HeroPlacement::LowerThird))
 
         .set_content(Title, |_|
         div![
@@ -92,7 +92,7 @@ fn markdown_editor(on_submit: impl FnOnce(String) -> Msg + 'static + Clone) -> N
                     class!["markdown-body"],  
                     md![&source],
                     el_ref(&preview_el.get()),
-                    s().overflow_auto().p(px(4)).b_color("gray").bg_color("white").b_width(px(1)).b_style_solid().h(pc(100)),
+                    s().overflow_auto().p(px(4)).b_color("gray").b_width(px(1)).b_style_solid().h(pc(100)),
                 ]
             ]
         ],    
@@ -280,9 +280,8 @@ pub fn sec_view<Ms: 'static>(args: SectionArgs, _root: Node<Ms>, _children: Vec<
     let mut c = code_example;
     let (title,sig,modal_content,code_example) = (args.title, args.sig, args.modal_content, args.code_example);
     div![
-        h3![
-            title
-        ],
+        // only the title is prose, the example button and modal keep their own styles
+        div![class!["prose"], h3![title]],
         pre![code![sig]],
         description.clone(),
 
@@ -369,8 +368,8 @@ fn md_view() -> Node<Msg> {
 fn markdown_content() -> Node<Msg>{
 div![
 
-    h1!["Hooks Tutorial"],
-md![r#"
+    prose!["# Hooks Tutorial"],
+prose![r#"
 
 ## Design
 
@@ -393,7 +392,7 @@ We will be making use of Seed Style features to assign with the styling and layo
 
 
 
-,md![r#"
+,prose![r#"
 ```
 // In in lib.rs...
 #[topo::nested]
@@ -1185,7 +1184,7 @@ fn modal_content() -> Option<Node<Msg>> {
 
 pub fn view(_model : &Model) -> Node<Msg>{
     
-    render_centred_article(_model, |_| {
+    render_centred_article_with_toc(_model, |_| {
     div![
         modal(),
         markdown_content(),
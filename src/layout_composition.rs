@@ -1,6 +1,8 @@
 use crate::app_styling::theme::Breakpoint;
+use crate::device_preview::{self, Device};
+use crate::layout_file::{self, FileLayouts, LayoutFileError, NamedArea};
 use crate::{Model, Msg};
-use seed::prelude::*;
+use seed::{prelude::*, *};
 use seed_style::*;
 use seed_style::{pc, px};
 ////////////////////////////////////////////////////
@@ -88,7 +90,9 @@ fn app_layout_small() -> Layout<AppArea> {
 
 // We have just defined the layouts for the main page, however we can also declare
 // our layouts for other parts of the page.
-// All layouts are completely content agnostic.
+// All layouts are completely content agnostic, so they do not even have to be written in Rust.
+// The header layouts are loaded from `layout_composition_header.ron` (see `layout_file.rs`),
+// which can be changed without touching any code.
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 enum HeaderArea {
@@ -99,21 +103,32 @@ enum HeaderArea {
 }
 impl LayoutArea for HeaderArea {}
 
-// We can actually use the build pattern to remove much cruft.
-fn header_layout_large() -> Layout<HeaderArea> {
-    use HeaderArea::*;
+// Layout files refer to areas by name.
+impl NamedArea for HeaderArea {
+    fn from_name(name: &str) -> Option<HeaderArea> {
+        use HeaderArea::*;
+        match name {
+            "Title" => Some(Title),
+            "Actions" => Some(Actions),
+            "Hamburger" => Some(Hamburger),
+            "Logo" => Some(Logo),
+            _ => None,
+        }
+    }
+}
 
-    Layout::areas(&[&[Logo, Title, Title, Title, Actions]]) // Horizontal layout of logo title and actions
-        .style(s().grid_template_columns("auto 1fr 1fr 1fr auto"))
-        .area_style(Actions, s().justify_self_right()) // ensure actions are justified to the right
-        .area_style(Logo, s().justify_self_left()) // ensure the logo is justified to the left
+thread_local! {
+    // The file is read once, the layouts are built from it on every render.
+    static HEADER_FILE: Result<FileLayouts<HeaderArea>, LayoutFileError> =
+        layout_file::from_ron(include_str!("layout_composition_header.ron"));
 }
 
-fn header_layout_small() -> Layout<HeaderArea> {
-    use HeaderArea::*;
-    Layout::areas(&[&[Title, Hamburger]])
-        .style(s().grid_template_columns("1fr auto"))
-        .area_style(Hamburger, s().justify_self_right())
+// A horizontal logo, title and actions header at larger screens, title and hamburger at smaller ones.
+fn header_layouts() -> Result<Vec<(Breakpoint, Layout<HeaderArea>)>, String> {
+    HEADER_FILE.with(|file| match file {
+        Ok(file) => Ok(file.layouts()),
+        Err(error) => Err(error.to_string()),
+    })
 }
 
 // The Main Content Layout  notice this has no named areas
@@ -149,32 +164,69 @@ fn main_content_layout() -> Layout<NoArea> {
 // https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Grid_Layout
 
 pub fn view(model: &Model) -> Node<Msg> {
+    use Device::*;
+
+    div![
+        example(model),
+        // The same composition at several screen widths, each frame chooses its own layouts.
+        section![
+            id!("device-previews"),
+            s().px(px(12)).py(px(24)),
+            prose![r#"## Device Previews
+
+Each frame chooses its layouts by its own width. Only the layouts are previewed, styles that depend
+on the screen size, such as `only_and_above`, are media queries and follow the width of the window
+rather than the frame."#],
+            device_preview::frames(model, &[Phone, Tablet, Desktop], preview),
+        ],
+    ]
+}
+
+fn example(model: &Model) -> Node<Msg> {
     use AppArea::*;
-    use Breakpoint::*;
 
     //  To enable different layouts on different breakpoints we add layouts to a composition and render
     //
     //  If no breakpoints match, the composition will choose the smallest one.
-    Composition::with_layouts(&[
-        (Small, app_layout_large()),
-        (ExtraSmall, app_layout_small()),
-    ])
+    Composition::with_layouts(&app_layouts())
     // We assign content views to the various named areas, typically you would use a function pointer
     // however here we show how to use nested compositions.
-    .set_content(Header, |model| {
-        Composition::with_layouts(&[
-            (Small, header_layout_large()),
-            (ExtraSmall, header_layout_small()),
-        ])
-        .render(model)
-    })
-    .set_content(MainContent, |model| {
-        Composition::with_layout(main_content_layout())
-            .mock_children("Photo", 7, px(250), px(250))
-            .render(model)
+    .set_content(Header, |model| match header_layouts() {
+        Ok(layouts) => Composition::with_layouts(&layouts).render(model),
+        // a mistake in the layout file shows up in place of the header
+        Err(error) => pre![error],
     })
+    .set_content(MainContent, main_content)
     // notice we have not hooked up Footer with anything yet...
     // Also notice we have not actually really created any content, we have just worked on the generate layout.
     // We now render the above composition.
     .render(model)
 }
+
+// The example as shown in a device frame. Compositions choose their layout by the window width, so
+// here the same layouts and content are put together with `device_preview::with_layouts`, which
+// chooses by the width of the frame instead.
+fn preview(model: &Model) -> Node<Msg> {
+    use AppArea::*;
+
+    device_preview::with_layouts(app_layouts())
+        .set_content(Header, |model| match header_layouts() {
+            Ok(layouts) => device_preview::with_layouts(layouts).render(model),
+            Err(error) => pre![error],
+        })
+        .set_content(MainContent, main_content)
+        .render(model)
+}
+
+fn app_layouts() -> Vec<(Breakpoint, Layout<AppArea>)> {
+    vec![
+        (Breakpoint::Small, app_layout_large()),
+        (Breakpoint::ExtraSmall, app_layout_small()),
+    ]
+}
+
+fn main_content(model: &Model) -> Node<Msg> {
+    Composition::with_layout(main_content_layout())
+        .mock_children("Photo", 7, px(250), px(250))
+        .render(model)
+}
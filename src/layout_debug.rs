@@ -0,0 +1,236 @@
+use crate::app_styling::theme::*;
+use crate::commands::{self, Command, CommandGroup};
+use crate::shortcuts::{self, Shortcut};
+use crate::Msg;
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::{pc, px};
+use seed_style::*;
+
+// Layout debug overlay
+// --------------------
+//
+// Outlines every named area of every `Composition` on the page, the app shell included, and labels
+// it with the area, the breakpoint of the layout in use and the active breakpoint, e.g.
+//
+//     Nav · Small layout at Large
+//
+// Compositions with a single layout, whose rule is outside of any media query, are labelled
+// `Nav · single layout`.
+//
+// Compositions give each layout a rule with `grid-template-areas`, inside a media query for the
+// breakpoints it covers. After each render the overlay reads those rules from the page's style
+// sheets, so only the grid containers of compositions are looked at. The layout in use on a grid is
+// its last rule whose media query matches the window, and the layout's breakpoint is the one the
+// media query starts at. The grid's children placed in a named area with `grid-area` are its areas.
+//
+// The marks are data attributes on the rendered elements and drawn by `layout_debug_styles`. The
+// view is left alone apart from areas that are not positioned, which are made `position: relative`
+// while outlined so that they can hold their label.
+
+const AREA_ATTRIBUTE: &str = "data-layout-area";
+// Set on areas that are not positioned, so that they can hold their label.
+const STATIC_ATTRIBUTE: &str = "data-layout-static";
+
+#[atom]
+fn show_layout_areas() -> bool {
+    false
+}
+
+pub fn toggle() {
+    show_layout_areas().update(|show| *show = !*show);
+    if !show_layout_areas().get() {
+        clear_marks();
+    }
+}
+
+pub fn register_commands() {
+    commands::register(Command::new(
+        CommandGroup::Action,
+        "Toggle layout area outlines",
+        || {
+            toggle();
+            Msg::NoOp
+        },
+    ));
+}
+
+pub fn register_shortcuts() {
+    shortcuts::register(Shortcut::new(&["l"], "Toggle layout area outlines", || {
+        toggle();
+        Msg::NoOp
+    }));
+}
+
+// Called from the root view, re-marks the areas once each render has reached the DOM.
+pub fn mark_after_render() {
+    if show_layout_areas().get() {
+        after_render(|_| mark_areas());
+    }
+}
+
+fn mark_areas() {
+    clear_marks();
+    let breakpoint = current_breakpoint();
+
+    for (grid, layout) in layout_grids() {
+        let children = grid.children();
+        for index in 0..children.length() {
+            let area = match children.item(index) {
+                Some(area) => area,
+                None => continue,
+            };
+            let name = match computed_property(&area, "grid-row-start") {
+                Some(name) if is_area_name(&name) => name,
+                _ => continue,
+            };
+            let label = match &layout {
+                None => format!("{} · single layout", name),
+                Some(layout) if *layout == breakpoint => format!("{} · {:?} layout", name, layout),
+                Some(layout) => format!("{} · {:?} layout at {:?}", name, layout, breakpoint),
+            };
+            let _ = area.set_attribute(AREA_ATTRIBUTE, &label);
+            if computed_property(&area, "position").as_deref() == Some("static") {
+                let _ = area.set_attribute(STATIC_ATTRIBUTE, "");
+            }
+        }
+    }
+}
+
+// The grid containers of compositions with the breakpoint of the layout each one uses, `None` for
+// compositions with a single layout.
+fn layout_grids() -> Vec<(web_sys::Element, Option<Breakpoint>)> {
+    let mut grids: Vec<(web_sys::Element, Option<Breakpoint>)> = vec![];
+    let sheets = document().style_sheets();
+    for index in 0..sheets.length() {
+        // reading the rules of a sheet from another origin fails, those are not the compositions'
+        let rules = match sheets
+            .item(index)
+            .and_then(|sheet| sheet.dyn_into::<web_sys::CssStyleSheet>().ok())
+            .and_then(|sheet| sheet.css_rules().ok())
+        {
+            Some(rules) => rules,
+            None => continue,
+        };
+        for (selector, layout) in layout_rules(&rules, None) {
+            for grid in elements(&selector) {
+                // a later rule wins, like in the cascade
+                match grids.iter_mut().find(|(found, _)| found.is_same_node(Some(grid.as_ref()))) {
+                    Some(found) => found.1 = layout.clone(),
+                    None => grids.push((grid, layout.clone())),
+                }
+            }
+        }
+    }
+    grids
+}
+
+// The selectors of the rules with `grid-template-areas` that apply at the current window width,
+// with the breakpoint their media query starts at, `None` for rules outside of media queries.
+fn layout_rules(
+    rules: &web_sys::CssRuleList,
+    breakpoint: Option<Breakpoint>,
+) -> Vec<(String, Option<Breakpoint>)> {
+    let mut found = vec![];
+    for index in 0..rules.length() {
+        let rule = match rules.item(index) {
+            Some(rule) => rule,
+            None => continue,
+        };
+        if let Some(media) = rule.dyn_ref::<web_sys::CssMediaRule>() {
+            let condition = media.media().media_text();
+            let matches = window()
+                .match_media(&condition)
+                .ok()
+                .flatten()
+                .map_or(false, |query| query.matches());
+            if matches {
+                // a query without a `min-width` starts at the smallest breakpoint
+                let breakpoint = breakpoint_for_width(min_width(&condition).unwrap_or(0));
+                found.extend(layout_rules(&media.css_rules(), Some(breakpoint)));
+            }
+        } else if let Some(style) = rule.dyn_ref::<web_sys::CssStyleRule>() {
+            let areas = style.style().get_property_value("grid-template-areas").unwrap_or_default();
+            if !areas.is_empty() && areas != "none" {
+                found.push((style.selector_text(), breakpoint.clone()));
+            }
+        }
+    }
+    found
+}
+
+// The pixels of a `min-width` in a media query, e.g. 600 for `(min-width: 600px)`.
+fn min_width(condition: &str) -> Option<u32> {
+    let start = condition.find("min-width:")? + "min-width:".len();
+    let value = condition[start..].trim_start();
+    let end = value.find("px")?;
+    value[..end].trim().parse::<f64>().ok().map(|width| width as u32)
+}
+
+fn clear_marks() {
+    for area in elements(&format!("[{}]", AREA_ATTRIBUTE)) {
+        let _ = area.remove_attribute(AREA_ATTRIBUTE);
+        let _ = area.remove_attribute(STATIC_ATTRIBUTE);
+    }
+}
+
+// Grid lines are `auto`, numbers or `span ..`, anything else names an area.
+fn is_area_name(value: &str) -> bool {
+    value != "auto"
+        && !value.starts_with("span")
+        && value.parse::<i32>().is_err()
+}
+
+fn elements(selector: &str) -> Vec<web_sys::Element> {
+    let nodes = match document().query_selector_all(selector) {
+        Ok(nodes) => nodes,
+        Err(_) => return vec![],
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::Element>().ok())
+        .collect()
+}
+
+fn computed_property(element: &web_sys::Element, property: &str) -> Option<String> {
+    window()
+        .get_computed_style(element)
+        .ok()
+        .flatten()
+        .and_then(|style| style.get_property_value(property).ok())
+}
+
+#[reaction]
+pub fn layout_debug_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(
+                "[data-layout-area]",
+                s().outline_width(px(2))
+                    .outline_style_dashed()
+                    .outline_color(Color::Secondary)
+                    .raw("outline-offset: -2px;"),
+            )
+            .style("[data-layout-area][data-layout-static]", s().position_relative())
+            .style(
+                "[data-layout-area]::after",
+                s().raw("content: attr(data-layout-area);")
+                    .position_absolute()
+                    .top(px(0))
+                    .left(px(0))
+                    .z_index("5")
+                    .max_width(pc(100))
+                    .px(px(4))
+                    .overflow_hidden()
+                    .white_space("nowrap")
+                    .raw("text-overflow: ellipsis;")
+                    .raw("pointer-events: none;")
+                    .font_family("monospace")
+                    .font_size(px(11))
+                    .line_height("16px")
+                    .color(Color::Background)
+                    .bg_color(Color::Secondary),
+            )
+            .activate_styles()
+    });
+}
@@ -0,0 +1,367 @@
+use crate::app_styling::theme::Breakpoint;
+use crate::area_grid::{Grid, GridError};
+use seed_style::*;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+// Layout files
+// ------------
+//
+// Layouts are content agnostic, so they can be kept out of the Rust code altogether and loaded from a
+// RON or JSON file. A file lists a layout for each breakpoint, as given to `Composition::with_layouts`:
+//
+//     (
+//         layouts: [
+//             (
+//                 breakpoint: "Small",
+//                 areas: [
+//                     "Header  Header",
+//                     "Sidebar MainContent",
+//                 ],
+//                 style: "grid-template-columns: 200px 1fr; grid-gap: 8px;",
+//                 area_styles: { "Sidebar": "padding: 12px;" },
+//             ),
+//         ],
+//     )
+//
+// `areas` has one string per row, written like `grid-template-areas` (see `area_grid.rs`), with the
+// names of the area enum's variants. `style` and `area_styles` are css declarations for the grid and
+// for single areas, both are optional. The same fields make up the JSON version.
+//
+// The area enum says which names it has by implementing `NamedArea`.
+//
+// Reading a file parses and checks it, the `Layout`s are built from the result with `layouts`, so a
+// view can read its file once and build the layouts on every render.
+
+pub trait NamedArea: Sized {
+    // The area called `name` in layout files, `None` if there is none. A `.` cell is looked up
+    // like any other name, so layouts with empty cells need an area for it.
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct LayoutFile {
+    layouts: Vec<LayoutSpec>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct LayoutSpec {
+    breakpoint: String,
+    areas: Vec<String>,
+    #[serde(default)]
+    style: String,
+    #[serde(default)]
+    area_styles: BTreeMap<String, String>,
+}
+
+// Layouts are numbered from 1 in the order of the file.
+#[derive(Debug, PartialEq)]
+pub enum LayoutFileError {
+    Syntax(String),
+    NoLayouts,
+    UnknownBreakpoint { layout: usize, name: String },
+    Grid { layout: usize, error: GridError },
+    UnknownArea { layout: usize, row: usize, name: String },
+    UnknownStyledArea { layout: usize, name: String },
+}
+
+impl fmt::Display for LayoutFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutFileError::Syntax(error) => write!(f, "The layout file can not be read: {}", error),
+            LayoutFileError::NoLayouts => write!(f, "The layout file has no layouts."),
+            LayoutFileError::UnknownBreakpoint { layout, name } => write!(
+                f,
+                "Layout {}: `{}` is not a breakpoint, use ExtraSmall, Small, Medium, Large or ExtraLarge.",
+                layout, name
+            ),
+            LayoutFileError::Grid { layout, error } => write!(f, "Layout {}: {}", layout, error),
+            LayoutFileError::UnknownArea { layout, row, name } => {
+                write!(f, "Layout {}: row {} has the unknown area `{}`.", layout, row, name)
+            }
+            LayoutFileError::UnknownStyledArea { layout, name } => write!(
+                f,
+                "Layout {}: `area_styles` has the unknown area `{}`.",
+                layout, name
+            ),
+        }
+    }
+}
+
+// The checked layouts of a file.
+pub struct FileLayouts<A> {
+    layouts: Vec<AreaLayout<A>>,
+}
+
+impl<A> FileLayouts<A>
+where
+    A: LayoutArea + Hash + Eq + Clone + Debug + 'static,
+{
+    // A layout for each breakpoint, ready for `Composition::with_layouts`.
+    pub fn layouts(&self) -> Vec<(Breakpoint, Layout<A>)> {
+        self.layouts.iter().map(build).collect()
+    }
+}
+
+pub fn from_ron<A: NamedArea>(text: &str) -> Result<FileLayouts<A>, LayoutFileError> {
+    let file = ron::de::from_str(text).map_err(|error| LayoutFileError::Syntax(error.to_string()))?;
+    Ok(FileLayouts { layouts: resolve(file)? })
+}
+
+// The app's layout files are RON, JSON is only read by the tests so far.
+#[cfg(test)]
+pub fn from_json<A: NamedArea>(text: &str) -> Result<FileLayouts<A>, LayoutFileError> {
+    let file = serde_json::from_str(text).map_err(|error| LayoutFileError::Syntax(error.to_string()))?;
+    Ok(FileLayouts { layouts: resolve(file)? })
+}
+
+// A checked layout of the file with its names looked up.
+#[derive(Debug, PartialEq)]
+struct AreaLayout<A> {
+    breakpoint: Breakpoint,
+    rows: Vec<Vec<A>>,
+    style: String,
+    area_styles: Vec<(A, String)>,
+}
+
+fn resolve<A: NamedArea>(file: LayoutFile) -> Result<Vec<AreaLayout<A>>, LayoutFileError> {
+    if file.layouts.is_empty() {
+        return Err(LayoutFileError::NoLayouts);
+    }
+
+    file.layouts
+        .into_iter()
+        .enumerate()
+        .map(|(index, spec)| {
+            let layout = index + 1;
+            let breakpoint = breakpoint_from_name(&spec.breakpoint).ok_or_else(|| {
+                LayoutFileError::UnknownBreakpoint { layout, name: spec.breakpoint.clone() }
+            })?;
+
+            // separate strings are separate rows, even when one of them is blank
+            let grid = Grid::parse(&spec.areas.join(" / "))
+                .map_err(|error| LayoutFileError::Grid { layout, error })?;
+            let rows = grid
+                .rows()
+                .iter()
+                .enumerate()
+                .map(|(index, row)| {
+                    row.iter()
+                        .map(|name| {
+                            A::from_name(name).ok_or_else(|| LayoutFileError::UnknownArea {
+                                layout,
+                                row: index + 1,
+                                name: name.clone(),
+                            })
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let area_styles = spec
+                .area_styles
+                .into_iter()
+                .map(|(name, style)| match A::from_name(&name) {
+                    Some(area) => Ok((area, style)),
+                    None => Err(LayoutFileError::UnknownStyledArea { layout, name }),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(AreaLayout {
+                breakpoint,
+                rows,
+                style: spec.style,
+                area_styles,
+            })
+        })
+        .collect()
+}
+
+fn build<A>(layout: &AreaLayout<A>) -> (Breakpoint, Layout<A>)
+where
+    A: LayoutArea + Hash + Eq + Clone + Debug + 'static,
+{
+    let rows = layout.rows.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let mut built = Layout::areas(&rows);
+    if !layout.style.trim().is_empty() {
+        built = built.style(s().raw(&layout.style));
+    }
+    for (area, style) in &layout.area_styles {
+        built = built.area_style(area.clone(), s().raw(style));
+    }
+    (layout.breakpoint.clone(), built)
+}
+
+fn breakpoint_from_name(name: &str) -> Option<Breakpoint> {
+    match name {
+        "ExtraSmall" => Some(Breakpoint::ExtraSmall),
+        "Small" => Some(Breakpoint::Small),
+        "Medium" => Some(Breakpoint::Medium),
+        "Large" => Some(Breakpoint::Large),
+        "ExtraLarge" => Some(Breakpoint::ExtraLarge),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum TestArea {
+        Header,
+        Main,
+        Empty,
+    }
+
+    impl NamedArea for TestArea {
+        fn from_name(name: &str) -> Option<TestArea> {
+            match name {
+                "Header" => Some(TestArea::Header),
+                "Main" => Some(TestArea::Main),
+                "." => Some(TestArea::Empty),
+                _ => None,
+            }
+        }
+    }
+
+    fn json(text: &str) -> Result<Vec<AreaLayout<TestArea>>, LayoutFileError> {
+        from_json(text).map(|file| file.layouts)
+    }
+
+    #[test]
+    fn reads_json_layouts() {
+        let layouts = json(
+            r#"{"layouts": [
+                {
+                    "breakpoint": "Small",
+                    "areas": ["Header Header", "Main ."],
+                    "style": "grid-gap: 8px;",
+                    "area_styles": {"Main": "padding: 12px;"}
+                },
+                {"breakpoint": "ExtraSmall", "areas": ["Header", "Main"]}
+            ]}"#,
+        )
+        .unwrap();
+
+        use TestArea::*;
+        assert_eq!(
+            layouts,
+            vec![
+                AreaLayout {
+                    breakpoint: Breakpoint::Small,
+                    rows: vec![vec![Header, Header], vec![Main, Empty]],
+                    style: "grid-gap: 8px;".to_string(),
+                    area_styles: vec![(Main, "padding: 12px;".to_string())],
+                },
+                AreaLayout {
+                    breakpoint: Breakpoint::ExtraSmall,
+                    rows: vec![vec![Header], vec![Main]],
+                    style: String::new(),
+                    area_styles: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_ron_layouts() {
+        let file: LayoutFile = ron::de::from_str(
+            r#"(
+                layouts: [
+                    (
+                        breakpoint: "Medium",
+                        areas: ["Header", "Main"],
+                        // comments are allowed in RON
+                        area_styles: { "Header": "height: 64px;" },
+                    ),
+                ],
+            )"#,
+        )
+        .unwrap();
+        let layouts = resolve::<TestArea>(file).unwrap();
+        assert_eq!(layouts[0].breakpoint, Breakpoint::Medium);
+        assert_eq!(layouts[0].rows, vec![vec![TestArea::Header], vec![TestArea::Main]]);
+        assert_eq!(
+            layouts[0].area_styles,
+            vec![(TestArea::Header, "height: 64px;".to_string())]
+        );
+    }
+
+    #[test]
+    fn unknown_areas_are_named() {
+        assert_eq!(
+            json(r#"{"layouts": [{"breakpoint": "Small", "areas": ["Header Header", "Main Sidebar"]}]}"#),
+            Err(LayoutFileError::UnknownArea {
+                layout: 1,
+                row: 2,
+                name: "Sidebar".to_string(),
+            })
+        );
+        assert_eq!(
+            json(r#"{"layouts": [{"breakpoint": "Small", "areas": ["Main"], "area_styles": {"Footer": ""}}]}"#),
+            Err(LayoutFileError::UnknownStyledArea {
+                layout: 1,
+                name: "Footer".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_rows_are_named() {
+        assert_eq!(
+            json(
+                r#"{"layouts": [
+                    {"breakpoint": "Small", "areas": ["Main"]},
+                    {"breakpoint": "ExtraSmall", "areas": ["Header Header", "Main"]}
+                ]}"#
+            ),
+            Err(LayoutFileError::Grid {
+                layout: 2,
+                error: GridError::RowLength {
+                    row: 2,
+                    found: 1,
+                    expected: 2,
+                },
+            })
+        );
+        assert_eq!(
+            json(r#"{"layouts": [{"breakpoint": "Small", "areas": ["Header", ""]}]}"#),
+            Err(LayoutFileError::Grid {
+                layout: 1,
+                error: GridError::EmptyRow { row: 2 },
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_breakpoints_are_named() {
+        assert_eq!(
+            json(r#"{"layouts": [{"breakpoint": "Tiny", "areas": ["Main"]}]}"#),
+            Err(LayoutFileError::UnknownBreakpoint {
+                layout: 1,
+                name: "Tiny".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn files_need_a_layout() {
+        assert_eq!(json(r#"{"layouts": []}"#), Err(LayoutFileError::NoLayouts));
+    }
+
+    #[test]
+    fn errors_read_as_sentences() {
+        let error = LayoutFileError::UnknownArea {
+            layout: 1,
+            row: 2,
+            name: "Sidebar".to_string(),
+        };
+        assert_eq!(error.to_string(), "Layout 1: row 2 has the unknown area `Sidebar`.");
+    }
+}
@@ -0,0 +1,187 @@
+use crate::compositions::*;
+use crate::device_preview::{self, Device};
+use crate::layout_playground::placeholder;
+use crate::layouts::{self, HeroPlacement, HolyGrail, Layouts};
+use crate::{Model, Msg};
+use seed::{prelude::*, *};
+use seed_style::px;
+use seed_style::*;
+
+// Layout presets page
+// -------------------
+//
+// Every preset of `layouts.rs` in phone and tablet sized frames, filled with placeholder areas.
+
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+enum GalleryArea {
+    Header,
+    Nav,
+    Sidebar,
+    Main,
+    Aside,
+    Footer,
+    Article,
+    Title,
+    Subtitle,
+    Stats,
+    Chart,
+    Activity,
+}
+impl LayoutArea for GalleryArea {}
+
+const DEVICES: &[Device] = &[Device::Phone, Device::Tablet];
+
+pub fn view(model: &Model) -> Node<Msg> {
+    use GalleryArea::*;
+
+    render_centred_article_with_toc(model, |model| {
+        div![
+            prose!["# Layout Presets"],
+            prose![r#"
+The `layouts` module has ready made layouts for common page structures. Each preset takes the areas
+of your own `LayoutArea` enum and returns a layout for every breakpoint it changes at, ready for
+`Composition::with_layouts`:
+
+```rust
+Composition::with_layouts(&layouts::sidebar_left(Nav, Main, "240px"))
+    .set_content(Nav, nav_view)
+    .set_content(Main, main_view)
+    .render(model)
+```
+
+At `ExtraSmall` every preset stacks its areas in a single column. The previews below show each
+preset at phone and tablet widths.
+
+## Sidebar Left
+
+A sidebar of a fixed width beside the main content, `sidebar_left(sidebar, main, sidebar_width)`.
+On phones the sidebar comes first.
+
+```rust
+layouts::sidebar_left(Sidebar, Main, "160px")
+```
+"#],
+            device_preview::frames(model, DEVICES, |model| {
+                preview(model, layouts::sidebar_left(Sidebar, Main, "160px"), &[Sidebar, Main])
+            }),
+            prose![r#"
+## Sidebar Right
+
+The main content with a sidebar to its right, `sidebar_right(main, sidebar, sidebar_width)`. On
+phones the sidebar follows the main content.
+
+```rust
+layouts::sidebar_right(Main, Aside, "160px")
+```
+"#],
+            device_preview::frames(model, DEVICES, |model| {
+                preview(model, layouts::sidebar_right(Main, Aside, "160px"), &[Main, Aside])
+            }),
+            prose![r#"
+## Holy Grail
+
+A header and footer around three columns. Below `Medium` the aside moves under the main content.
+
+```rust
+layouts::holy_grail(
+    HolyGrail {
+        header: Header,
+        nav: Nav,
+        main: Main,
+        aside: Aside,
+        footer: Footer,
+    },
+    "120px",
+)
+```
+"#],
+            device_preview::frames(model, DEVICES, |model| {
+                let grail = HolyGrail {
+                    header: Header,
+                    nav: Nav,
+                    main: Main,
+                    aside: Aside,
+                    footer: Footer,
+                };
+                preview(
+                    model,
+                    layouts::holy_grail(grail, "120px"),
+                    &[Header, Nav, Main, Aside, Footer],
+                )
+            }),
+            prose![r#"
+## Centred Article
+
+A single column of at most the given width, centred in the page like the articles of these docs.
+
+```rust
+layouts::centred_article(Article, "480px")
+```
+"#],
+            device_preview::frames(model, DEVICES, |model| {
+                preview(model, layouts::centred_article(Article, "480px"), &[Article])
+            }),
+            prose![r#"
+## Hero
+
+A centred title above its subtitle. `HeroPlacement::Centre` puts them halfway down the banner,
+`HeroPlacement::LowerThird` two thirds of the way down as in the banners of the home pages. On
+phones long titles wrap.
+
+```rust
+layouts::hero(Title, Subtitle, HeroPlacement::Centre)
+```
+"#],
+            device_preview::frames(model, DEVICES, |model| {
+                preview(
+                    model,
+                    layouts::hero(Title, Subtitle, HeroPlacement::Centre),
+                    &[Title, Subtitle],
+                )
+            }),
+            prose![r#"
+## Card Grid
+
+As many columns as fit cards of a minimum width. It has no named areas, cards are added as
+children, and needs no breakpoints as narrow screens get a single column.
+
+```rust
+Composition::with_layout(layouts::card_grid("160px"))
+    .mock_children("Card", 6, px(160), px(100))
+```
+"#],
+            device_preview::frames(model, DEVICES, |model| {
+                Composition::with_layout(layouts::card_grid("160px"))
+                    .mock_children("Card", 6, px(160), px(100))
+                    .render(model)
+            }),
+            prose![r#"
+## Dashboard
+
+A header over a navigation column and panels two to a row, one to a row below `Medium`.
+A last panel without a neighbour takes the whole row.
+
+```rust
+layouts::dashboard(Header, Nav, &[Stats, Chart, Activity], "120px")
+```
+"#],
+            device_preview::frames(model, DEVICES, |model| {
+                preview(
+                    model,
+                    layouts::dashboard(Header, Nav, &[Stats, Chart, Activity], "120px"),
+                    &[Header, Nav, Stats, Chart, Activity],
+                )
+            }),
+        ]
+    })
+}
+
+// The preset with a placeholder in each of `areas`.
+fn preview(model: &Model, layouts: Layouts<GalleryArea>, areas: &[GalleryArea]) -> Node<Msg> {
+    let mut composition = device_preview::with_layouts(layouts);
+    for area in areas {
+        let label = format!("{:?}", area);
+        composition = composition.set_content(area.clone(), move |_| placeholder(&label));
+    }
+    div![s().min_height(px(240)), composition.render(model)]
+}
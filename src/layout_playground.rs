@@ -0,0 +1,247 @@
+use crate::app_styling::theme::*;
+use crate::compositions::*;
+use crate::{Model, Msg};
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::{pc, px};
+use seed_style::*;
+use std::fmt;
+
+use crate::area_grid::{Grid, EMPTY_CELL};
+
+// Layout playground
+// -----------------
+//
+// Type an area grid and see it laid out by a `Composition`, then copy the Rust code for it.
+// The grid is parsed by `area_grid.rs`, its names become `PlaygroundArea`s at runtime.
+
+const GRID_INPUT_ID: &str = "playground_grid";
+const GRID_ERROR_ID: &str = "playground_grid_error";
+
+const DEFAULT_GRID: &str = "header header\nsidebar main\nfooter footer";
+
+// An area named by the user. The name is the area's debug output, which the layout uses for
+// `grid-template-areas`, so it has to be the bare name.
+#[derive(Hash, PartialEq, Eq, Clone)]
+struct PlaygroundArea(String);
+
+impl fmt::Debug for PlaygroundArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl LayoutArea for PlaygroundArea {
+    fn is_empty(&self) -> bool {
+        self.0 == EMPTY_CELL
+    }
+}
+
+#[topo::nested]
+pub fn view(model: &Model) -> Node<Msg> {
+    let grid_text = use_state(|| DEFAULT_GRID.to_string());
+    let columns = use_state(|| "200px 1fr".to_string());
+    let rows = use_state(|| "auto 1fr auto".to_string());
+    let gap = use_state(|| "8px".to_string());
+
+    render_centred_article(model, move |model| {
+        let grid = Grid::parse(&grid_text.get())
+            .and_then(|grid| grid.check_variants().map(|_| grid));
+
+        div![
+            prose!["# Layout Playground"],
+            prose![r#"
+Write a grid of area names, one row per line or with rows separated by `/`, the same way as
+`grid-template-areas`. Every row needs the same number of cells, each area has to be a rectangle
+and `.` leaves a cell empty.
+
+The grid is turned into a `Layout::areas` with an area for each name and every area is filled
+with a placeholder. Below the preview is the Rust code for the same layout.
+"#],
+            div![
+                class!["playground-controls"],
+                div![
+                    class!["playground-grid"],
+                    label![attrs! {At::For => GRID_INPUT_ID}, "Areas"],
+                    textarea![
+                        attrs! {
+                            At::Id => GRID_INPUT_ID,
+                            At::Rows => "5",
+                            At::from("spellcheck") => "false",
+                            At::AutoComplete => "off",
+                            At::Value => grid_text.get(),
+                        },
+                        if grid.is_err() {
+                            Some(attrs! {
+                                At::from("aria-invalid") => "true",
+                                At::from("aria-describedby") => GRID_ERROR_ID,
+                            })
+                        } else {
+                            None
+                        },
+                        grid_text.input_ev(Ev::Input, |text, input| *text = input),
+                    ],
+                    grid.as_ref().err().map(|error| {
+                        div![
+                            class!["playground-error"],
+                            attrs! {
+                                At::Id => GRID_ERROR_ID,
+                                At::from("role") => "alert",
+                            },
+                            error.to_string(),
+                        ]
+                    }),
+                ],
+                text_setting("Columns", "playground_columns", columns),
+                text_setting("Rows", "playground_rows", rows),
+                text_setting("Gap", "playground_gap", gap),
+            ],
+            match &grid {
+                Ok(grid) => vec![
+                    h2!["Preview"],
+                    preview(model, grid, &columns.get(), &rows.get(), &gap.get()),
+                    h2!["Rust Code"],
+                    pre![code![
+                        class!["language-rust"],
+                        grid.to_rust(&columns.get(), &rows.get(), &gap.get()),
+                    ]],
+                ],
+                // the last good preview is not kept, the error above explains what to fix
+                Err(_) => vec![],
+            },
+        ]
+    })
+}
+
+fn text_setting(label_text: &str, id: &str, value: StateAccess<String>) -> Node<Msg> {
+    div![
+        class!["playground-setting"],
+        label![attrs! {At::For => id}, label_text],
+        input![
+            attrs! {
+                At::Id => id,
+                At::Type => "text",
+                At::from("spellcheck") => "false",
+                At::AutoComplete => "off",
+                At::Value => value.get(),
+            },
+            value.input_ev(Ev::Input, |value, input| *value = input),
+        ],
+    ]
+}
+
+fn preview(model: &Model, grid: &Grid, columns: &str, rows: &str, gap: &str) -> Node<Msg> {
+    let area_rows = grid
+        .rows()
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|name| PlaygroundArea(name.clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let area_rows = area_rows.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+    let mut style = s().min_height(px(320));
+    if !columns.trim().is_empty() {
+        style = style.grid_template_columns(columns);
+    }
+    if !rows.trim().is_empty() {
+        style = style.grid_template_rows(rows);
+    }
+    if !gap.trim().is_empty() {
+        style = style.grid_gap(gap);
+    }
+
+    let mut composition = Composition::with_layout(Layout::areas(&area_rows).style(style));
+    for name in grid.areas() {
+        let label = name.to_string();
+        composition = composition.set_content(PlaygroundArea(label.clone()), move |_| {
+            placeholder(&label)
+        });
+    }
+    div![class!["playground-preview"], composition.render(model)]
+}
+
+// Like the boxes of `mock_children`, labelled with the area name. Also used by the layout presets page.
+pub fn placeholder(name: &str) -> Node<Msg> {
+    div![
+        s().display_flex()
+            .align_items_center()
+            .justify_content_center()
+            .h(pc(100))
+            .min_height(px(64))
+            .p(px(8))
+            .radius(px(4))
+            .b_width(px(2))
+            .b_style_dashed()
+            .b_color(Color::Primary)
+            .bg_color(Color::MutedPrimary)
+            .color(Color::DarkPrimary)
+            .font_family("monospace"),
+        name,
+    ]
+}
+
+#[reaction]
+pub fn playground_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(
+                ".playground-controls",
+                s().display_grid()
+                    .grid_template_columns("repeat(3, minmax(0px, 1fr))")
+                    .grid_gap(px(12))
+                    .my(px(24)),
+            )
+            .style(
+                ".playground-controls",
+                s().only_and_below(Breakpoint::ExtraSmall)
+                    .grid_template_columns("minmax(0px, 1fr)"),
+            )
+            .style(
+                ".playground-grid",
+                s().grid_column_start("1").grid_column_end("-1"),
+            )
+            .style(
+                ".playground-controls label",
+                s().display_block()
+                    .mb(px(4))
+                    .font_size(FontSize::Caption)
+                    .color(Color::MutedText),
+            )
+            .style(
+                ".playground-controls textarea, .playground-controls input",
+                s().w(pc(100))
+                    .px(px(12))
+                    .py(px(6))
+                    .radius(px(4))
+                    .b_width(px(1))
+                    .b_style_solid()
+                    .b_color(Color::MutedSecondary)
+                    .bg_color(Color::Background)
+                    .color(Color::MainText)
+                    .font_family("monospace")
+                    .font_size(FontSize::Body),
+            )
+            .style(
+                ".playground-controls textarea[aria-invalid]",
+                s().b_color(Color::Secondary),
+            )
+            .style(
+                ".playground-error",
+                s().mt(px(4))
+                    .font_size(FontSize::Caption)
+                    .color(Color::Secondary),
+            )
+            .style(
+                ".playground-preview",
+                s().p(px(8))
+                    .radius(px(4))
+                    .b_width(px(1))
+                    .b_style_solid()
+                    .b_color(Color::MutedSecondary),
+            )
+            .activate_styles()
+    });
+}
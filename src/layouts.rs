@@ -0,0 +1,245 @@
+use crate::app_styling::theme::Breakpoint;
+use seed_style::*;
+use seed_style::{pc, px};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+// Layout presets
+// --------------
+//
+// Ready made layouts for common page structures, filled with the caller's own areas:
+//
+//     Composition::with_layouts(&layouts::sidebar_left(Nav, Main, "240px"))
+//
+// Presets return a layout for each breakpoint they change at, to be given to
+// `Composition::with_layouts` or `device_preview::with_layouts`. At `Breakpoint::ExtraSmall`
+// they stack their areas in a single column.
+//
+// The presets are shown on the layout presets page, `layout_gallery.rs`.
+
+// The area types presets work with, which is any enum that can be a layout area, deriving
+// `Hash, PartialEq, Eq, Clone, Debug` like the areas in this app.
+pub trait PresetArea: LayoutArea + Hash + Eq + Clone + Debug + 'static {}
+
+impl<A: LayoutArea + Hash + Eq + Clone + Debug + 'static> PresetArea for A {}
+
+pub type Layouts<A> = Vec<(Breakpoint, Layout<A>)>;
+
+const GAP: u32 = 16;
+
+fn areas<A: PresetArea>(rows: Vec<Vec<A>>) -> Layout<A> {
+    let rows = rows.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    Layout::areas(&rows)
+}
+
+// `sidebar` beside `main`, `sidebar_width` wide.
+pub fn sidebar_left<A: PresetArea>(sidebar: A, main: A, sidebar_width: &str) -> Layouts<A> {
+    vec![
+        (
+            Breakpoint::Small,
+            areas(vec![vec![sidebar.clone(), main.clone()]]).style(
+                s().grid_template_columns(format!("{} minmax(0px, 1fr)", sidebar_width))
+                    .grid_gap(px(GAP)),
+            ),
+        ),
+        (Breakpoint::ExtraSmall, stacked(vec![sidebar, main], Some(1))),
+    ]
+}
+
+// `main` with `sidebar` to its right, `sidebar_width` wide. Below `Breakpoint::Small` the sidebar
+// follows the main content.
+pub fn sidebar_right<A: PresetArea>(main: A, sidebar: A, sidebar_width: &str) -> Layouts<A> {
+    vec![
+        (
+            Breakpoint::Small,
+            areas(vec![vec![main.clone(), sidebar.clone()]]).style(
+                s().grid_template_columns(format!("minmax(0px, 1fr) {}", sidebar_width))
+                    .grid_gap(px(GAP)),
+            ),
+        ),
+        (Breakpoint::ExtraSmall, stacked(vec![main, sidebar], Some(0))),
+    ]
+}
+
+// The areas of the holy grail layout.
+#[derive(Clone)]
+pub struct HolyGrail<A> {
+    pub header: A,
+    pub nav: A,
+    pub main: A,
+    pub aside: A,
+    pub footer: A,
+}
+
+// A header and footer across the page, with `main` between `nav` and `aside` columns of
+// `side_width`. At `Breakpoint::Small` the aside moves under the main content.
+pub fn holy_grail<A: PresetArea>(grail: HolyGrail<A>, side_width: &str) -> Layouts<A> {
+    let HolyGrail {
+        header,
+        nav,
+        main,
+        aside,
+        footer,
+    } = grail;
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let medium = areas(vec![
+        vec![header.clone(), header.clone(), header.clone()],
+        vec![nav.clone()   , main.clone()  , aside.clone() ],
+        vec![footer.clone(), footer.clone(), footer.clone()],
+    ]);
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let small = areas(vec![
+        vec![header.clone(), header.clone()],
+        vec![nav.clone()   , main.clone()  ],
+        vec![nav.clone()   , aside.clone() ],
+        vec![footer.clone(), footer.clone()],
+    ]);
+
+    vec![
+        (
+            Breakpoint::Medium,
+            medium.style(
+                s().grid_template_columns(format!("{0} minmax(0px, 1fr) {0}", side_width))
+                    .grid_template_rows("auto 1fr auto")
+                    .grid_gap(px(GAP))
+                    .min_height(pc(100.)),
+            ),
+        ),
+        (
+            Breakpoint::Small,
+            small.style(
+                s().grid_template_columns(format!("{} minmax(0px, 1fr)", side_width))
+                    .grid_template_rows("auto 1fr auto auto")
+                    .grid_gap(px(GAP))
+                    .min_height(pc(100.)),
+            ),
+        ),
+        (
+            Breakpoint::ExtraSmall,
+            stacked(vec![header, nav, main, aside, footer], Some(2)),
+        ),
+    ]
+}
+
+// `article` centred in the page and at most `max_width` wide, like the documentation pages.
+pub fn centred_article<A: PresetArea>(article: A, max_width: &str) -> Layouts<A> {
+    let layout = |padding: u32| {
+        areas(vec![vec![article.clone()]]).style(
+            s().grid_template_columns(format!("minmax(0px, {})", max_width))
+                .justify_content_center()
+                .px(px(padding)),
+        )
+    };
+    vec![
+        (Breakpoint::Small, layout(24)),
+        (Breakpoint::ExtraSmall, layout(12)),
+    ]
+}
+
+// Where a hero's title meets its subtitle.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum HeroPlacement {
+    // halfway down the banner
+    Centre,
+    // two thirds of the way down, as in the banners of the home pages
+    LowerThird,
+}
+
+// A centred `title` above its `subtitle`, for banners.
+pub fn hero<A: PresetArea>(title: A, subtitle: A, placement: HeroPlacement) -> Layouts<A> {
+    let rows = match placement {
+        HeroPlacement::Centre => "1fr 1fr",
+        HeroPlacement::LowerThird => "2fr 1fr",
+    };
+    let layout = || {
+        areas(vec![vec![title.clone()], vec![subtitle.clone()]])
+            .area_style(title.clone(), s().align_self_flex_end())
+            .area_style(subtitle.clone(), s().align_self_flex_start())
+    };
+    vec![
+        (
+            Breakpoint::Small,
+            layout().style(
+                s().grid_template_columns("auto")
+                    .grid_template_rows(rows)
+                    .justify_content_center(),
+            ),
+        ),
+        // long titles wrap instead of overflowing the screen
+        (
+            Breakpoint::ExtraSmall,
+            layout().style(
+                s().grid_template_columns("minmax(0px, 1fr)")
+                    .grid_template_rows(rows)
+                    .justify_items_center()
+                    .text_align_center()
+                    .px(px(GAP)),
+            ),
+        ),
+    ]
+}
+
+// As many columns of cards as fit, each at least `min_card_width` wide, for children added with
+// `add_child`. It needs no breakpoints, on screens narrower than a card there is one column of
+// full width cards.
+pub fn card_grid(min_card_width: &str) -> Layout<NoArea> {
+    Layout::grid(
+        s().grid_template_columns(format!(
+            "repeat(auto-fit, minmax(min(100%, {}), 1fr))",
+            min_card_width
+        ))
+        .grid_auto_flow_row()
+        .grid_gap(px(GAP))
+        .w(pc(100.)),
+    )
+}
+
+// A header over a `nav` column and `panels` two to a row, or one to a row at `Breakpoint::Small`.
+// A last panel without a neighbour takes the whole row.
+//
+// Panics without any panels.
+pub fn dashboard<A: PresetArea>(header: A, nav: A, panels: &[A], nav_width: &str) -> Layouts<A> {
+    assert!(!panels.is_empty(), "a dashboard needs at least one panel");
+
+    let with_columns = |columns: usize| {
+        let mut rows = vec![vec![header.clone(); columns + 1]];
+        for chunk in panels.chunks(columns) {
+            let mut row = vec![nav.clone()];
+            row.extend(chunk.iter().cloned());
+            // the last panel stretches over the missing ones
+            while row.len() < columns + 1 {
+                row.push(chunk[chunk.len() - 1].clone());
+            }
+            rows.push(row);
+        }
+        areas(rows).style(
+            s().grid_template_columns(format!(
+                "{} repeat({}, minmax(0px, 1fr))",
+                nav_width, columns
+            ))
+            .grid_template_rows("auto")
+            .grid_auto_rows("minmax(120px, auto)")
+            .grid_gap(px(GAP)),
+        )
+    };
+
+    let mut small_screen = vec![header.clone(), nav.clone()];
+    small_screen.extend(panels.iter().cloned());
+
+    vec![
+        (Breakpoint::Medium, with_columns(2)),
+        (Breakpoint::Small, with_columns(1)),
+        (Breakpoint::ExtraSmall, stacked(small_screen, None)),
+    ]
+}
+
+// One area per row, the row of the `main` area, if any, takes the remaining height.
+fn stacked<A: PresetArea>(areas_in_order: Vec<A>, main: Option<usize>) -> Layout<A> {
+    let rows = (0..areas_in_order.len())
+        .map(|index| if Some(index) == main { "1fr" } else { "auto" })
+        .collect::<Vec<_>>()
+        .join(" ");
+    areas(areas_in_order.into_iter().map(|area| vec![area]).collect())
+        .style(s().grid_template_rows(rows).grid_gap(px(GAP)))
+}
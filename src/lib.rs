@@ -2,18 +2,33 @@
 use seed::{prelude::*, *};
 use seed_hooks::*;
 use seed_style::*;
-use seed_style::vw;
+use seed_style::{px, vw};
+
+// Markdown documentation, in the `prose` scope of `themed_global_styles`. Only this markdown is
+// styled as prose, the components rendered between it keep their own styles.
+macro_rules! prose {
+    ($md:expr) => {
+        seed::div![seed::class!["prose"], seed::md!($md)]
+    };
+}
 
 mod button_styling;
 mod compositions;
 mod extending_seed;
+mod footer;
 mod getting_started;
 mod header;
 mod home;
 mod home_style;
 mod simple_layout;
 mod layout_composition;
+mod layout_playground;
+mod layout_gallery;
+mod layouts;
+mod area_grid;
+mod layout_file;
 mod nav;
+mod responsive_image;
 mod responsive_styling;
 mod theming;
 mod thousandtest;
@@ -22,10 +37,24 @@ mod hooks_home;
 mod hooks_getting_started;
 mod hooks_api;
 mod hooks_tutorial;
+mod highlight;
+mod code_blocks;
+mod anchors;
+mod toc;
+mod swipe;
+mod search;
+mod commands;
+mod palette;
+mod shortcuts;
+mod breadcrumbs;
+mod layout_debug;
+mod device_preview;
+mod breakpoint_badge;
 
 
 mod app_styling;
-use app_styling::global_styles::{init_styles,themed_global_styles};
+use app_styling::global_styles::{init_styles,print_styles,themed_global_styles};
+use app_styling::markdown_styles::markdown_body_styles;
 use app_styling::theme::*;
 
 // This app shows how to use most features of a proposed styling system for Seed.
@@ -42,11 +71,13 @@ use app_styling::theme::*;
 //  ---------------------------------------
 
 #[derive(Clone, PartialEq)]
-enum Page {
+pub enum Page {
     Home,
     StyleHome,
     SimpleLayout,
     LayoutComposition,
+    LayoutPlayground,
+    LayoutGallery,
     Theming,
     ResponsiveStyling,
     ButtonStyling,
@@ -72,6 +103,8 @@ pub struct Model {
 pub enum Msg {
     SubmitMarkdownHtml(String),
     WindowResized,
+    ScrollToHeading(String),
+    GoTo(String),
     NoOp,
 }
 
@@ -87,6 +120,21 @@ fn update(msg: Msg, _model: &mut Model, orders: &mut impl Orders<Msg>) {
             conditionally_skip_rendering::<Breakpoint, _, _, _>(|| my_theme(), orders)
         }
         Msg::NoOp => {},
+        Msg::GoTo(link) => {
+            // like following a link, `link` is a path with an optional heading anchor
+            if let Ok(url) = link.parse::<Url>() {
+                url.go_and_push();
+                orders.notify(subs::UrlChanged(url));
+            }
+        }
+        Msg::ScrollToHeading(id) => {
+            // The heading only exists once the page has been rendered.
+            orders.after_next_render(move |_| {
+                if let Some(heading) = document().get_element_by_id(&id) {
+                    heading.scroll_into_view();
+                }
+            });
+        }
         Msg::SubmitMarkdownHtml(html) => log!(html),
     }
 }
@@ -98,6 +146,7 @@ fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
     load_app_themes(&[default_colors_theme, default_breakpoint_theme, my_theme]);
     // setup a page state accessor, which is modified as part of a Url Changed subscription
     let page = use_state(|| Page::Home);
+    breakpoint_badge::enable_from_url(&url);
 
     orders
         .subscribe(move |subs::UrlChanged(mut url)| {
@@ -110,6 +159,8 @@ fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
                 ["load_test"] => Page::LoadTest,
                 ["simple_layout"] => Page::SimpleLayout,
                 ["layout"] => Page::LayoutComposition,
+                ["layout_playground"] => Page::LayoutPlayground,
+                ["layout_presets"] => Page::LayoutGallery,
                 ["getting_started"] => Page::GettingStarted,
                 ["extending_seed"] => Page::ExtendingSeed,
                 ["hooks_home"] => Page::HooksHome,
@@ -119,11 +170,22 @@ fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
                 _ =>  Page::Home,
             };
 
+            let heading = url.hash().cloned();
+
             if page.get() != new_page {
-                window().scroll_to_with_x_and_y(0., 0.);
+                if heading.is_none() {
+                    window().scroll_to_with_x_and_y(0., 0.);
+                }
+                breadcrumbs::clear_heading();
+                toc::stop_spying();
                 page.set(new_page);
             }
-            Msg::NoOp
+
+            // links to a heading anchor, e.g. `/hooks_api#use_state`
+            match heading {
+                Some(id) => Msg::ScrollToHeading(id),
+                None => Msg::NoOp,
+            }
             }
             // 
         )
@@ -135,12 +197,51 @@ fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
 
     // We subscribe to a window resize event in the init in order to handle window resizing
     orders.stream(streams::window_event(Ev::Resize, |_| Msg::WindowResized));
+    orders.stream(streams::window_event(Ev::Resize, |_| breakpoint_badge::on_window_resize()));
+    orders.stream(streams::window_event(Ev::Scroll, |_| header::on_window_scroll()));
+    orders.stream(streams::window_event(Ev::Scroll, |_| breadcrumbs::on_window_scroll()));
     themed_global_styles().get();
+    markdown_body_styles().get();
+    print_styles().get();
+    highlight::highlight_styles().get();
+    code_blocks::code_block_styles().get();
+    anchors::heading_anchor_styles().get();
+    toc::toc_styles().get();
+    search::search_styles().get();
+    search::load_index();
+    palette::palette_styles().get();
+    shortcuts::shortcut_styles().get();
+    breadcrumbs::breadcrumb_styles().get();
+    layout_debug::layout_debug_styles().get();
+    layout_playground::playground_styles().get();
+    device_preview::device_preview_styles().get();
+    breakpoint_badge::breakpoint_badge_styles().get();
+    footer::footer_styles().get();
+    orders.stream(streams::window_event(Ev::KeyDown, |event| {
+        shortcuts::on_window_key_down(event.unchecked_ref())
+    }));
+
+    let show_drawer = use_state(|| false);
+
+    // Commands for the command palette
+    nav::register_commands(show_drawer);
+    header::register_commands();
+    search::register_commands();
+    thousandtest::register_commands();
+    layout_debug::register_commands();
+    breakpoint_badge::register_commands();
+
+    // Keyboard shortcuts, listed in this order by the `?` overlay
+    nav::register_shortcuts(page);
+    search::register_shortcuts();
+    header::register_shortcuts();
+    palette::register_shortcuts();
+    layout_debug::register_shortcuts();
+    breakpoint_badge::register_shortcuts();
+    shortcuts::register_shortcuts();
+
     // Our model just needs the state accessors for page, show_drawer, and themes
-    Model {
-        page,
-        show_drawer: use_state(|| false),
-    }
+    Model { page, show_drawer }
 }
 
 // Default app start...
@@ -154,12 +255,22 @@ pub fn start() {
 enum AppAreas {
     Header,
     MainContent,
+    Footer,
 }
 impl LayoutArea for AppAreas {}
 
 #[topo::nested]
 pub fn view(model: &Model) -> Node<Msg> {
-    themed_view(model)
+    let drawer_drag = use_state(|| None);
+    let mut root = themed_view(model);
+    swipe::add_swipe_handlers(&mut root, model, drawer_drag);
+    if let Node::Element(root) = &mut root {
+        root.add_child(palette::view());
+        root.add_child(shortcuts::help_view());
+        root.add_child(breakpoint_badge::view());
+    }
+    layout_debug::mark_after_render();
+    root
 }
 
 pub fn themed_view(model: &Model) -> Node<Msg> {
@@ -167,7 +278,7 @@ pub fn themed_view(model: &Model) -> Node<Msg> {
     use Breakpoint::*;
 
     Composition::with_layout(
-        Layout::areas(&[&[Header], &[MainContent]])
+        Layout::areas(&[&[Header], &[MainContent], &[Footer]])
             .area_style(
                 Header,
                 s().only_and_below(ExtraSmall)
@@ -177,12 +288,24 @@ pub fn themed_view(model: &Model) -> Node<Msg> {
                     .width(vw(100))
                     .top("0px")
                     .right("0px")
-                    .left("0px"),
+                    .left("0px")
+                    // slides up out of view when the header is hidden
+                    .transform(format!(
+                        "translateY(calc({} - {}px))",
+                        header::HEADER_OFFSET,
+                        header::HEADER_HEIGHT
+                    ))
+                    .transition("transform 0.2s ease-out 0s"),
             )
-            .style(s().grid_template_rows("auto 1fr")),
+            .area_style(
+                MainContent,
+                s().only_and_below(ExtraSmall).pt(px(header::HEADER_HEIGHT)),
+            )
+            .style(s().grid_template_rows("auto 1fr auto")),
     )
     .set_content(Header, |model| header::view(model))
     .set_content(MainContent, |model| main_layout(model))
+    .set_content(Footer, |model| footer::view(model))
     .render(model)
 }
 
@@ -207,12 +330,14 @@ fn main_layout(model: &Model) -> Node<Msg> {
 
 #[topo::nested]
 fn main_view(model: &Model) -> Node<Msg> {
-    match model.page.get() {
+    let mut page = match model.page.get() {
         Page::Home => home::view(model),
         Page::StyleHome => home_style::view(model),
         Page::ButtonStyling => button_styling::view(model),
         Page::SimpleLayout => simple_layout::view(model),
         Page::LayoutComposition => layout_composition::view(model),
+        Page::LayoutPlayground => layout_playground::view(model),
+        Page::LayoutGallery => layout_gallery::view(model),
         Page::Theming => theming::view(model),
         Page::ResponsiveStyling => responsive_styling::view(model),
         Page::LoadTest => thousandtest::view(model),
@@ -222,6 +347,8 @@ fn main_view(model: &Model) -> Node<Msg> {
         Page::HooksGettingStarted => hooks_getting_started::view(model),
         Page::HooksApi => hooks_api::view(model),
         Page::HooksTutorial => hooks_tutorial::view(model),
-
-    }
+    };
+    highlight::highlight_code_blocks(&mut page);
+    anchors::add_heading_anchors(&mut page, &mut anchors::Slugger::new());
+    page
 }
@@ -1,19 +1,196 @@
 use crate::app_styling::theme::*;
-use crate::{Model, Msg};
+use crate::commands::{self, Command, CommandGroup};
+use crate::shortcuts::{self, Shortcut};
+use crate::{anchors, header, Model, Msg, Page};
 use seed::{prelude::*, *};
 use seed_hooks::*;
 use seed_style::*;
-use seed_style::{px, vh};
+use seed_style::{pc, px, vh};
 
+// A titled group of links in the drawer.
+pub struct NavSection {
+    pub title: &'static str,
+    pub links: Vec<NavLink>,
+}
+
+pub struct NavLink {
+    pub page: Page,
+    pub href: &'static str,
+    pub label: &'static str,
+}
+
+impl NavLink {
+    fn new(page: Page, href: &'static str, label: &'static str) -> NavLink {
+        NavLink { page, href, label }
+    }
+}
+
+pub fn sections() -> Vec<NavSection> {
+    vec![
+        NavSection {
+            title: "Seed Style",
+            links: vec![
+                NavLink::new(Page::StyleHome, "/style_home", "Style Home"),
+                NavLink::new(Page::GettingStarted, "/getting_started", "Getting Started"),
+                NavLink::new(Page::ButtonStyling, "/buttons", "Button Styling"),
+                NavLink::new(Page::Theming, "/theming", "Theming Support"),
+                NavLink::new(Page::ResponsiveStyling, "/responsive_styling", "Responsive Styling"),
+                NavLink::new(Page::SimpleLayout, "/simple_layout", "Simple Layout Primitives"),
+                NavLink::new(Page::LayoutComposition, "/layout", "Layout Example"),
+                NavLink::new(Page::LayoutPlayground, "/layout_playground", "Layout Playground"),
+                NavLink::new(Page::LayoutGallery, "/layout_presets", "Layout Presets"),
+                NavLink::new(Page::ExtendingSeed, "/extending_seed", "Extending Seed"),
+                NavLink::new(Page::LoadTest, "/load_test", "Load Test"),
+            ],
+        },
+        NavSection {
+            title: "Seed Hooks",
+            links: vec![
+                NavLink::new(Page::HooksHome, "/hooks_home", "Hooks Home"),
+                NavLink::new(Page::HooksGettingStarted, "/hooks_getting_started", "Hooks Getting Started"),
+                NavLink::new(Page::HooksApi, "/hooks_api", "Hooks Api"),
+                NavLink::new(Page::HooksTutorial, "/hooks_tutorial", "Hooks Tutorial"),
+            ],
+        },
+    ]
+}
+
+pub fn register_commands(show_drawer: StateAccess<bool>) {
+    for section in sections() {
+        for link in section.links {
+            commands::register(Command::go_to(
+                CommandGroup::Page,
+                format!("Go to {}", link.label),
+                link.href,
+            ));
+        }
+    }
+    commands::register(Command::new(
+        CommandGroup::Action,
+        "Open navigation drawer",
+        move || {
+            show_drawer.set(true);
+            Msg::NoOp
+        },
+    ));
+}
+
+// Moves through the pages in the order of the drawer.
+pub fn register_shortcuts(page: StateAccess<Page>) {
+    shortcuts::register(Shortcut::new(&["j", "["], "Previous page", move || {
+        go_to_adjacent_page(page, -1)
+    }));
+    shortcuts::register(Shortcut::new(&["k", "]"], "Next page", move || {
+        go_to_adjacent_page(page, 1)
+    }));
+}
+
+fn go_to_adjacent_page(page: StateAccess<Page>, step: isize) -> Msg {
+    let hrefs = sections()
+        .into_iter()
+        .flat_map(|section| section.links)
+        .map(|link| (link.page, link.href))
+        .collect::<Vec<_>>();
+    let current = page.get();
+
+    // pages outside the drawer, such as Home, go to the first page
+    let index = match hrefs.iter().position(|(page, _)| *page == current) {
+        Some(index) => index as isize + step,
+        None => 0,
+    };
+    match hrefs.get(index.max(0) as usize) {
+        Some((_, href)) => Msg::GoTo(href.to_string()),
+        None => Msg::NoOp,
+    }
+}
+
+// Titles of the sections the user has collapsed, kept in local storage across reloads.
+const COLLAPSED_KEY: &str = "nav_collapsed_sections";
+
+#[topo::nested]
 pub fn view(model: &Model) -> Node<Msg> {
+    let collapsed = use_state(|| {
+        LocalStorage::get::<Vec<String>>(COLLAPSED_KEY).unwrap_or_default()
+    });
+
+    // Bring the active link into view when the drawer first renders a page, but not on every
+    // re-render, so that the drawer keeps any position the user has scrolled it to.
+    // The section of the page is expanded first, afterwards it can be collapsed as usual.
+    let scrolled_to = use_state(|| None::<Page>);
+    if scrolled_to.get() != Some(model.page.get()) {
+        let page = model.page.get();
+        if let Some(section) = sections()
+            .into_iter()
+            .find(|section| section.links.iter().any(|link| link.page == page))
+        {
+            if is_collapsed(collapsed, section.title) {
+                collapsed.update(|titles| toggle_section(titles, section.title));
+            }
+        }
+        scrolled_to.set(Some(page));
+        after_render(|_| scroll_active_link_into_view());
+    }
+
+    // However the drawer is opened or closed (toggle, backdrop, Escape, a link, or growing
+    // out of the small breakpoint) focus and the body scroll lock follow it.
+    let was_open = use_state(|| false);
+    if was_open.get() != model.show_drawer.get() {
+        let open = model.show_drawer.get();
+        was_open.set(open);
+        after_render(move |_| on_drawer_toggled(open));
+    }
+
+    let show_drawer = model.show_drawer;
+
     div![
         s().only_and_above(SeedBreakpoint::Small).width(px(256)).position_sticky().top(px(0)),
+        s().media("@media print").display_none(),
+    if model.show_drawer.get() {
+        div![
+            s().position_fixed()
+                .top(header::HEADER_OFFSET)
+                .right(px(0))
+                .bottom(px(0))
+                .left(px(0))
+                .z_index("1")
+                .bg_color("rgba(0,0,0,0.4)"),
+            model.show_drawer.on_click(|v| *v = false)
+        ]
+    } else {
+        empty![]
+    },
     div![
+        id!(DRAWER_ID),
         only_and_above(SeedBreakpoint::Small, || { model.show_drawer.set(false); empty![]}),
+        if model.show_drawer.get() {
+            Some(attrs! {
+                At::from("role") => "dialog",
+                At::from("aria-modal") => "true",
+                At::from("aria-label") => "Navigation",
+            })
+        } else {
+            None
+        },
+        keyboard_ev(Ev::KeyDown, move |event| {
+            if !show_drawer.get() {
+                return None;
+            }
+            match event.key().as_str() {
+                "Escape" => {
+                    show_drawer.set(false);
+                    Some(Msg::NoOp)
+                }
+                "Tab" => {
+                    trap_focus(&event);
+                    None
+                }
+                _ => None,
+            }
+        }),
         s()
             .position_fixed()
             .left(px(0))
-            .top(px(64))
+            .top(header::HEADER_OFFSET)
             .bottom(px(0))
             .z_index("1")
             .min_width(px(0))
@@ -24,13 +201,18 @@ pub fn view(model: &Model) -> Node<Msg> {
             .padding_bottom(px(32))
             .padding_right(px(8))
             .padding_left(px(4))
-            .transition("transform 0.2s ease-out 0s")
             .background_color(Color::Background),
+        // a closed drawer is hidden once it has slid away so its links cannot be tabbed to
         if model.show_drawer.get() {
             s().transform("translateX(0px)")
                 .box_shadow(Shadow::RightEdge)
+                .visibility("visible")
+                .transition("transform 0.2s ease-out 0s, visibility 0s linear 0s")
         } else {
-            s().transform("translateX(-100%)").box_shadow("none")
+            s().transform("translateX(-100%)")
+                .box_shadow("none")
+                .visibility("hidden")
+                .transition("transform 0.2s ease-out 0s, visibility 0s linear 0.2s")
         },
             s().only_and_above(Breakpoint::Small)
                 .box_shadow("none")
@@ -38,99 +220,192 @@ pub fn view(model: &Model) -> Node<Msg> {
                 .position_sticky()
                 .bottom_auto()
                 .transform("none")
+                .visibility("visible")
         ,
-        div![s().pt(px(30)).font_size(px(20)).font_weight_v900(),"Seed Style"]
-        ,
-        ul![
-            s().pl(px(12)).pr(px(38)).pt(px(12)),
-            s().style_descendant("a").text_decoration_none(),
-            s().style_child("li")
-                .font_size(px(16))
-                .font_weight_v700()
-                .py(px(8))
-                .my(px(4))
-                .text_decoration_none(),
-            s().style_child("li").list_style_type_none(),
-            li![a![
-                attrs! {At::Href => "/style_home"},
-                "Style Home",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/getting_started"},
-                "Getting Started",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/buttons"},
-                "Button Styling",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/theming"},
-                "Theming Support",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/responsive_styling"},
-                "Responsive Styling",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/simple_layout"},
-                "Simple Layout Primitives",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/layout"},
-                "Layout Example",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/extending_seed"},
-                "Extending Seed",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/load_test"},
-                "Load Test",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
+        sections()
+            .iter()
+            .map(|section| section_view(model, section, collapsed))
+            .collect::<Vec<_>>()
+    ]
+    ]
+}
+
+pub const DRAWER_ID: &str = "nav_drawer";
+// The "☰" button in the header that opens and closes the drawer.
+pub const TOGGLE_ID: &str = "nav_drawer_toggle";
 
+fn section_view(
+    model: &Model,
+    section: &NavSection,
+    collapsed: StateAccess<Vec<String>>,
+) -> Node<Msg> {
+    let title = section.title;
+    let expanded = !is_collapsed(collapsed, title);
+    let list_id = format!("nav_section_{}", anchors::slugify(title));
+
+    div![
+        button![
+            attrs! {
+                At::Type => "button",
+                At::from("aria-expanded") => expanded.to_string(),
+                At::from("aria-controls") => list_id.as_str(),
+            },
+            s().display_flex()
+                .align_items_center()
+                .justify_content_space_between()
+                .w(pc(100))
+                .pt(px(30))
+                .pr(px(38))
+                .b_width(px(0))
+                .bg_color("transparent")
+                .color(Color::MainText)
+                .font_size(px(20))
+                .font_weight_v900()
+                .text_align_left()
+                .cursor_pointer(),
+            s().hover().color(Color::Primary),
+            span![title],
+            span![
+                attrs! {At::from("aria-hidden") => "true"},
+                s().font_size(px(14)).transition("transform 0.2s ease-out 0s"),
+                if expanded {
+                    s()
+                } else {
+                    s().transform("rotate(-90deg)")
+                },
+                "▾"
+            ],
+            collapsed.on_click(move |titles| toggle_section(titles, title))
         ],
-        div![s().pt(px(30)).font_size(px(20)).font_weight_v900(),"Seed Hooks"]
-        ,
         ul![
+            id!(list_id),
             s().pl(px(12)).pr(px(38)).pt(px(12)),
+            if expanded { s() } else { s().display_none() },
             s().style_descendant("a").text_decoration_none(),
             s().style_child("li")
                 .font_size(px(16))
                 .font_weight_v700()
                 .py(px(8))
-                .my(px(4))
-                .text_decoration_none(),
-            s().style_child("li").list_style_type_none(),
-            li![a![
-                attrs! {At::Href => "/hooks_home"},
-                "Hooks Home",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/hooks_getting_started"},
-                "Hooks Getting Started",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/hooks_api"},
-                "Hooks Api",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
-            li![a![
-                attrs! {At::Href => "/hooks_tutorial"},
-                "Hooks Tutorial",
-                model.show_drawer.on_click(|v| *v = false)
-            ]],
+                .my(px(4)),
+            section.links.iter().map(|link| nav_link(model, link)),
         ]
     ]
-    ]
+}
+
+fn is_collapsed(collapsed: StateAccess<Vec<String>>, title: &str) -> bool {
+    collapsed.get_with(|titles| titles.iter().any(|collapsed| collapsed == title))
+}
+
+fn toggle_section(collapsed: &mut Vec<String>, title: &str) {
+    match collapsed.iter().position(|collapsed| collapsed == title) {
+        Some(index) => {
+            collapsed.remove(index);
+        }
+        None => collapsed.push(title.to_string()),
+    }
+    let _ = LocalStorage::insert(COLLAPSED_KEY, collapsed);
+}
+
+fn nav_link(model: &Model, link: &NavLink) -> Node<Msg> {
+    let active = model.page.get() == link.page;
+
+    li![a![
+        attrs! {At::Href => link.href},
+        s().display_block()
+            .pl(px(8))
+            .border_left_width(px(3))
+            .border_left_style("solid")
+            .border_left_color("transparent"),
+        if active {
+            Some(attrs! {At::from("aria-current") => "page"})
+        } else {
+            None
+        },
+        if active {
+            s().color(Color::Primary).border_left_color(Color::Primary)
+        } else {
+            s()
+        },
+        link.label,
+        model.show_drawer.on_click(|v| *v = false)
+    ]]
+}
+
+// Scrolls the drawer, and only the drawer, so that the active link is in the middle of it.
+fn scroll_active_link_into_view() {
+    let drawer = match get_html_element_by_id(DRAWER_ID) {
+        Some(drawer) => drawer,
+        None => return,
+    };
+    if let Ok(Some(link)) = drawer.query_selector("[aria-current='page']") {
+        if let Ok(link) = link.dyn_into::<web_sys::HtmlElement>() {
+            let top = link.offset_top() - (drawer.client_height() - link.offset_height()) / 2;
+            drawer.set_scroll_top(top.max(0));
+        }
+    }
+}
+
+fn on_drawer_toggled(open: bool) {
+    let body = document().body();
+    if open {
+        if let Some(body) = body {
+            let _ = body.set_attribute("data-drawer-open", "");
+        }
+        if let Some(first) = focusable_in_drawer().first() {
+            let _ = first.focus();
+        }
+    } else {
+        if let Some(body) = body {
+            let _ = body.remove_attribute("data-drawer-open");
+        }
+        if let Some(toggle) = get_html_element_by_id(TOGGLE_ID) {
+            let _ = toggle.focus();
+        }
+    }
+}
+
+// Keeps Tab and Shift+Tab cycling through the links of the open drawer.
+fn trap_focus(event: &web_sys::KeyboardEvent) {
+    let focusable = focusable_in_drawer();
+    let (first, last) = match (focusable.first(), focusable.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return,
+    };
+    let active = document().active_element();
+    let is_active = |el: &web_sys::HtmlElement| {
+        active.as_ref().map_or(false, |active| {
+            let el: &web_sys::Element = el;
+            active == el
+        })
+    };
+
+    if event.shift_key() && is_active(first) {
+        event.prevent_default();
+        let _ = last.focus();
+    } else if !event.shift_key() && is_active(last) {
+        event.prevent_default();
+        let _ = first.focus();
+    }
+}
+
+// The elements of the drawer that can take focus, leaving out those that are not rendered such as
+// the links of collapsed sections.
+fn focusable_in_drawer() -> Vec<web_sys::HtmlElement> {
+    let mut focusable = vec![];
+    if let Some(drawer) = get_html_element_by_id(DRAWER_ID) {
+        if let Ok(nodes) = drawer.query_selector_all("a[href], button, input, [tabindex]") {
+            for index in 0..nodes.length() {
+                if let Some(el) = nodes
+                    .item(index)
+                    .and_then(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+                {
+                    // `display: none` elements, or those inside one, have no offset parent
+                    if el.offset_parent().is_some() {
+                        focusable.push(el);
+                    }
+                }
+            }
+        }
+    }
+    focusable
 }
@@ -0,0 +1,210 @@
+use crate::app_styling::theme::*;
+use crate::commands::{self, Command};
+use crate::shortcuts::{self, Shortcut};
+use crate::Msg;
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::{pc, px};
+use seed_style::*;
+
+// Command palette
+// ---------------
+//
+// Ctrl+K (or Cmd+K) opens an overlay that fuzzy matches every registered command, see `commands.rs`.
+// Arrow keys move the selection, Enter runs it and Escape closes the palette.
+
+const PALETTE_INPUT_ID: &str = "command_palette_input";
+const MAX_MATCHES: usize = 10;
+
+#[atom]
+fn palette_open() -> bool {
+    false
+}
+
+fn close() {
+    palette_open().set(false);
+}
+
+pub fn register_shortcuts() {
+    // browsers use Ctrl+K for their own search box, the shortcut prevents that
+    shortcuts::register(
+        Shortcut::new(&["Ctrl+K"], "Open the command palette", || {
+            palette_open().update(|open| *open = !*open);
+            Msg::NoOp
+        })
+        .in_inputs(),
+    );
+}
+
+#[topo::nested]
+pub fn view() -> Node<Msg> {
+    let query = use_state(String::new);
+    let selected = use_state(|| 0_usize);
+
+    // Start from a blank palette each time it opens.
+    let was_open = use_state(|| false);
+    let open = palette_open().get();
+    if open != was_open.get() {
+        was_open.set(open);
+        if open {
+            query.set(String::new());
+            selected.set(0);
+            after_render(|_| {
+                if let Some(input) = get_html_element_by_id(PALETTE_INPUT_ID) {
+                    let _ = input.focus();
+                }
+            });
+        }
+    }
+    if !open {
+        return empty![];
+    }
+
+    let matches = commands::matching(&query.get(), MAX_MATCHES);
+    let count = matches.len();
+    let current = selected.get().min(count.saturating_sub(1));
+    let chosen = matches.get(current).cloned();
+
+    div![
+        class!["palette-backdrop"],
+        mouse_ev(Ev::Click, |_| {
+            close();
+            Msg::NoOp
+        }),
+        div![
+            class!["palette"],
+            attrs! {
+                At::from("role") => "dialog",
+                At::from("aria-modal") => "true",
+                At::from("aria-label") => "Command palette",
+            },
+            // clicks inside the palette should not reach the backdrop
+            ev(Ev::Click, |event| event.stop_propagation()),
+            input![
+                attrs! {
+                    At::Id => PALETTE_INPUT_ID,
+                    At::Type => "text",
+                    At::Placeholder => "Go to a page or run a command…",
+                    At::AutoComplete => "off",
+                    At::from("aria-label") => "Command",
+                    At::from("aria-controls") => "command_palette_list",
+                    At::Value => query.get(),
+                },
+                query.input_ev(Ev::Input, move |query, text| {
+                    *query = text;
+                    selected.set(0);
+                }),
+                keyboard_ev(Ev::KeyDown, move |event| match event.key().as_str() {
+                    "ArrowDown" => {
+                        event.prevent_default();
+                        selected.set((current + 1).min(count.saturating_sub(1)));
+                        Some(Msg::NoOp)
+                    }
+                    "ArrowUp" => {
+                        event.prevent_default();
+                        selected.set(current.saturating_sub(1));
+                        Some(Msg::NoOp)
+                    }
+                    "Enter" => chosen.as_ref().map(run),
+                    "Escape" => {
+                        close();
+                        Some(Msg::NoOp)
+                    }
+                    _ => None,
+                }),
+            ],
+            if matches.is_empty() {
+                div![class!["palette-empty"], "No matching commands"]
+            } else {
+                ul![
+                    id!("command_palette_list"),
+                    attrs! {At::from("role") => "listbox"},
+                    matches
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, command)| command_view(command, index == current)),
+                ]
+            }
+        ]
+    ]
+}
+
+fn command_view(command: Command, is_selected: bool) -> Node<Msg> {
+    li![
+        class!["palette-selected" => is_selected],
+        attrs! {
+            At::from("role") => "option",
+            At::from("aria-selected") => is_selected.to_string(),
+        },
+        span![class!["palette-group"], command.group.name()],
+        span![command.title.as_str()],
+        mouse_ev(Ev::Click, move |_| run(&command)),
+    ]
+}
+
+fn run(command: &Command) -> Msg {
+    close();
+    command.run()
+}
+
+#[reaction]
+pub fn palette_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(
+                ".palette-backdrop",
+                s().position_fixed()
+                    .top(px(0))
+                    .right(px(0))
+                    .bottom(px(0))
+                    .left(px(0))
+                    .z_index("10")
+                    .display_flex()
+                    .justify_content_center()
+                    .align_items_flex_start()
+                    .pt("15vh")
+                    .bg_color("rgba(0,0,0,0.4)"),
+            )
+            .style(
+                ".palette",
+                s().w("min(560px, 92vw)")
+                    .radius(px(6))
+                    .overflow_hidden()
+                    .bg_color(Color::Background)
+                    .box_shadow(Shadow::RightEdge),
+            )
+            .style(
+                ".palette input",
+                s().w(pc(100))
+                    .p(px(16))
+                    .b_width(px(0))
+                    .bb_width(px(1))
+                    .b_style_solid()
+                    .b_color(Color::MutedSecondary)
+                    .bg_color(Color::Background)
+                    .color(Color::MainText)
+                    .font_size(FontSize::H3)
+                    .outline_style_none(),
+            )
+            .style(".palette ul", s().max_height("50vh").overflow_y_auto().py(px(4)))
+            .style(
+                ".palette li",
+                s().display_flex()
+                    .align_items_center()
+                    .px(px(16))
+                    .py(px(8))
+                    .cursor_pointer(),
+            )
+            .style(".palette li", s().hover().bg_color(Color::MutedPrimary))
+            .style(".palette li.palette-selected", s().bg_color(Color::MutedPrimary).color(Color::Primary))
+            .style(
+                ".palette-group",
+                s().w(px(72))
+                    .flex_shrink("0")
+                    .font_size(FontSize::Caption)
+                    .color(Color::MutedText),
+            )
+            .style(".palette-empty", s().p(px(16)).color(Color::MutedText))
+            .activate_styles()
+    });
+}
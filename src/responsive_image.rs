@@ -0,0 +1,260 @@
+use crate::app_styling::theme::*;
+use crate::header;
+use seed::{prelude::*, *};
+use seed_style::pc;
+use seed_style::*;
+
+// Responsive images
+// -----------------
+//
+// An image with a file for each breakpoint, so that small screens download small files:
+//
+//     ResponsiveImage::new("The dark theme card")
+//         .source(Breakpoint::ExtraSmall, "/public/dark_small.png", 394, 131)
+//         .source(Breakpoint::Small, "/public/dark.png", 395, 563)
+//         .view()
+//
+// Like a composition's layouts, a source is used from its breakpoint upwards until the next
+// breakpoint that has one, and below the smallest breakpoint with a source that source is used.
+// The choice is left to the browser with a `picture` element, one `source` per breakpoint with a
+// `min-width` media query from the breakpoint ranges of the theme (see `breakpoint_range`).
+//
+// Sources can also be given for the light or dark theme only, these replace the source of their
+// breakpoint while that theme is active.
+//
+// Images are lazy loaded. The width and height of each file, in pixels, are set on its element so the
+// browser reserves the image's space before it loads and the page does not shift.
+//
+// Images need alt text. Images that are pure decoration, and say nothing the page does not, are
+// made with `ResponsiveImage::decorative` which gives them an empty alt.
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+impl ColorScheme {
+    pub fn active() -> ColorScheme {
+        if header::using_dark_theme().get() {
+            ColorScheme::Dark
+        } else {
+            ColorScheme::Light
+        }
+    }
+
+    fn other(self) -> ColorScheme {
+        match self {
+            ColorScheme::Light => ColorScheme::Dark,
+            ColorScheme::Dark => ColorScheme::Light,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct ImageSource {
+    breakpoint: Breakpoint,
+    scheme: Option<ColorScheme>,
+    src: String,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct ResponsiveImage {
+    alt: String,
+    sources: Vec<ImageSource>,
+}
+
+impl ResponsiveImage {
+    // `alt` is read by screen readers and shown when the image can not be loaded.
+    //
+    // Panics when `alt` is blank, use `decorative` for images without meaning.
+    pub fn new(alt: &str) -> ResponsiveImage {
+        assert!(
+            !alt.trim().is_empty(),
+            "a responsive image needs alt text, use `ResponsiveImage::decorative` for decoration"
+        );
+        ResponsiveImage {
+            alt: alt.to_string(),
+            sources: vec![],
+        }
+    }
+
+    // An image that is pure decoration, which screen readers skip.
+    pub fn decorative() -> ResponsiveImage {
+        ResponsiveImage {
+            alt: String::new(),
+            sources: vec![],
+        }
+    }
+
+    // The file `src`, `width` by `height` pixels, for `breakpoint` and up.
+    pub fn source(self, breakpoint: Breakpoint, src: &str, width: u32, height: u32) -> ResponsiveImage {
+        self.add_source(breakpoint, None, src, width, height)
+    }
+
+    // Like `source`, only while `scheme` is the active theme.
+    pub fn themed_source(
+        self,
+        scheme: ColorScheme,
+        breakpoint: Breakpoint,
+        src: &str,
+        width: u32,
+        height: u32,
+    ) -> ResponsiveImage {
+        self.add_source(breakpoint, Some(scheme), src, width, height)
+    }
+
+    fn add_source(
+        mut self,
+        breakpoint: Breakpoint,
+        scheme: Option<ColorScheme>,
+        src: &str,
+        width: u32,
+        height: u32,
+    ) -> ResponsiveImage {
+        self.sources.push(ImageSource {
+            breakpoint,
+            scheme,
+            src: src.to_string(),
+            width,
+            height,
+        });
+        self
+    }
+
+    // The sources used with `scheme` active, one per breakpoint from the largest breakpoint down.
+    // A source for `scheme` wins over one for any theme, a later source over an earlier one. An
+    // image with sources for the other theme only uses those rather than showing nothing.
+    fn sources_for(&self, scheme: ColorScheme) -> Vec<&ImageSource> {
+        match self.sources_of(scheme) {
+            sources if sources.is_empty() => self.sources_of(scheme.other()),
+            sources => sources,
+        }
+    }
+
+    fn sources_of(&self, scheme: ColorScheme) -> Vec<&ImageSource> {
+        let mut chosen: Vec<&ImageSource> = vec![];
+        for source in &self.sources {
+            if source.scheme.map_or(false, |only| only != scheme) {
+                continue;
+            }
+            match chosen.iter_mut().find(|chosen| chosen.breakpoint == source.breakpoint) {
+                Some(chosen) if chosen.scheme.is_some() && source.scheme.is_none() => {}
+                Some(chosen) => *chosen = source,
+                None => chosen.push(source),
+            }
+        }
+        chosen.sort_by_key(|source| std::cmp::Reverse(breakpoint_range(&source.breakpoint).0));
+        chosen
+    }
+
+    // Renders nothing for an image without any sources.
+    pub fn view<Ms>(&self) -> Node<Ms> {
+        let mut sources = self.sources_for(ColorScheme::active());
+        let fallback = match sources.pop() {
+            Some(fallback) => fallback,
+            None => return empty![],
+        };
+
+        picture![
+            class!["responsive-image"],
+            sources
+                .iter()
+                .map(|source| {
+                    source![attrs! {
+                        At::from("media") => min_width_query(&source.breakpoint),
+                        At::from("srcset") => source.src,
+                        At::Width => source.width,
+                        At::Height => source.height,
+                    }]
+                })
+                .collect::<Vec<_>>(),
+            img![
+                s().display_block().max_width(pc(100.)).height("auto"),
+                attrs! {
+                    At::Src => fallback.src,
+                    At::Alt => self.alt,
+                    At::Width => fallback.width,
+                    At::Height => fallback.height,
+                    At::from("loading") => "lazy",
+                    At::from("decoding") => "async",
+                },
+            ],
+        ]
+    }
+}
+
+fn min_width_query(breakpoint: &Breakpoint) -> String {
+    format!("(min-width: {}px)", breakpoint_range(breakpoint).0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> ResponsiveImage {
+        ResponsiveImage::new("A card")
+            .source(Breakpoint::Small, "card.png", 395, 563)
+            .source(Breakpoint::ExtraSmall, "card_small.png", 394, 131)
+            .source(Breakpoint::Large, "card_big.png", 790, 1126)
+    }
+
+    fn srcs(image: &ResponsiveImage, scheme: ColorScheme) -> Vec<&str> {
+        image
+            .sources_for(scheme)
+            .iter()
+            .map(|source| source.src.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn sources_are_largest_breakpoint_first() {
+        assert_eq!(
+            srcs(&image(), ColorScheme::Light),
+            vec!["card_big.png", "card.png", "card_small.png"]
+        );
+    }
+
+    #[test]
+    fn themed_sources_replace_their_breakpoint() {
+        let image = image().themed_source(ColorScheme::Dark, Breakpoint::Small, "card_dark.png", 395, 563);
+        assert_eq!(
+            srcs(&image, ColorScheme::Dark),
+            vec!["card_big.png", "card_dark.png", "card_small.png"]
+        );
+        assert_eq!(
+            srcs(&image, ColorScheme::Light),
+            vec!["card_big.png", "card.png", "card_small.png"]
+        );
+    }
+
+    #[test]
+    fn themed_sources_win_in_any_order() {
+        let image = ResponsiveImage::new("A card")
+            .themed_source(ColorScheme::Light, Breakpoint::Small, "card_light.png", 395, 563)
+            .source(Breakpoint::Small, "card.png", 395, 563);
+        assert_eq!(srcs(&image, ColorScheme::Light), vec!["card_light.png"]);
+        assert_eq!(srcs(&image, ColorScheme::Dark), vec!["card.png"]);
+    }
+
+    #[test]
+    fn images_for_one_theme_are_shown_in_the_other() {
+        let image = ResponsiveImage::new("A card")
+            .themed_source(ColorScheme::Dark, Breakpoint::Small, "card_dark.png", 395, 563);
+        assert_eq!(srcs(&image, ColorScheme::Light), vec!["card_dark.png"]);
+        assert!(srcs(&ResponsiveImage::new("A card"), ColorScheme::Light).is_empty());
+    }
+
+    #[test]
+    fn media_queries_use_the_breakpoint_ranges() {
+        assert_eq!(min_width_query(&Breakpoint::Medium), "(min-width: 960px)");
+    }
+
+    #[test]
+    #[should_panic]
+    fn alt_text_is_required() {
+        ResponsiveImage::new("  ");
+    }
+}
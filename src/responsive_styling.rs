@@ -1,5 +1,6 @@
 use crate::app_styling::theme::*;
 
+use crate::responsive_image::ResponsiveImage;
 use crate::{Model, Msg};
 use seed::{prelude::*, *};
 
@@ -7,7 +8,7 @@ use seed_style::*;
 use seed_style::{pc, px};
 
 pub fn view(_model: &Model) -> Node<Msg> {
-    div![md![r#"
+    div![prose![r#"
 # Responsive Styling
 
 It is trivial to setup responsive styling within Seed Style.  Support is first class and includes everything from media query support, values
@@ -133,9 +134,40 @@ fn update(msg: Msg, _model: &mut Model, orders: &mut impl Orders<Msg>) {
 
 Just ensure you pass in a copy of the theme that defines the relevant breakpoints.
 
+## Responsive images
+
+Images can also change with the breakpoint. A `ResponsiveImage` takes a file for each breakpoint, used from that breakpoint
+upwards, and renders a `picture` element so that the browser only downloads the file for the current window width:
+
+```rust
+ResponsiveImage::new("A card showing the first season of Dark")
+    .source(Breakpoint::ExtraSmall, "/public/dark_small.png", 394, 131)
+    .source(Breakpoint::Small, "/public/dark.png", 395, 563)
+    .source(Breakpoint::Large, "/public/dark_big.png", 395, 563)
+    .view()
+```
+
+Each file is given with its size in pixels, which reserves the image's space while it lazily loads. Alt text is required,
+images that are only decoration are made with `ResponsiveImage::decorative()`.
+
+Files for just the light or dark theme are added with `themed_source`:
+
+```rust
+    .themed_source(ColorScheme::Light, Breakpoint::Small, "/public/light.png", 395, 563)
+```
+
+Resize the window to see the image below change:
+"#],
+        ResponsiveImage::new("A card showing the first season of Dark")
+            .source(Breakpoint::ExtraSmall, "/public/dark_small.png", 394, 131)
+            .source(Breakpoint::Small, "/public/dark.png", 395, 563)
+            .source(Breakpoint::Large, "/public/dark_big.png", 395, 563)
+            .view(),
+        prose![r#"
 ## Summary
 
 We have covered several ways in which your application can be trivially responsive, from simple value scales on breakpoints, to
-media queries, conditional styling and finally total conditional rendering.
-"#],]
+media queries, conditional styling, total conditional rendering and finally responsive images.
+"#],
+    ]
 }
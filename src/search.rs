@@ -0,0 +1,240 @@
+use crate::app_styling::theme::*;
+use crate::commands::{self, Command, CommandGroup};
+use crate::shortcuts::{self, Shortcut};
+use crate::{nav, Msg, Page};
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::{pc, px};
+use seed_style::*;
+
+mod extract;
+mod index;
+use index::{SearchIndex, SearchResult};
+
+// Documentation search
+// --------------------
+//
+// The index holds every section of every page listed in the nav drawer. Page sources are included
+// at compile time and split into sections on startup (see `extract.rs`), searching and ranking
+// happens in `index.rs`.
+//
+// Results link to the page and heading anchor, `/hooks_api#use_state`, which the router scrolls to.
+
+pub const SEARCH_INPUT_ID: &str = "search_input";
+
+const MAX_RESULTS: usize = 8;
+
+thread_local! {
+    static INDEX: SearchIndex = build_index();
+}
+
+// Builds the index up front so that the first search does not have to.
+pub fn load_index() {
+    INDEX.with(|_| ());
+}
+
+pub fn register_shortcuts() {
+    shortcuts::register(Shortcut::new(&["/"], "Focus search", || {
+        if let Some(input) = get_html_element_by_id(SEARCH_INPUT_ID) {
+            let _ = input.focus();
+        }
+        Msg::NoOp
+    }));
+}
+
+// Every heading in the index can be jumped to from the command palette.
+pub fn register_commands() {
+    INDEX.with(|index| {
+        for document in index.documents() {
+            if let Some(heading) = &document.heading {
+                commands::register(Command::go_to(
+                    CommandGroup::Heading,
+                    format!("{} › {}", document.page_title, heading),
+                    document.link(),
+                ));
+            }
+        }
+    });
+}
+
+fn build_index() -> SearchIndex {
+    let mut documents = vec![];
+    for section in nav::sections() {
+        for link in section.links {
+            documents.extend(extract::page_documents(
+                link.label,
+                link.href,
+                page_source(&link.page),
+            ));
+        }
+    }
+    SearchIndex::new(documents)
+}
+
+fn page_source(page: &Page) -> &'static str {
+    match page {
+        Page::Home => include_str!("home.rs"),
+        Page::StyleHome => include_str!("home_style.rs"),
+        Page::ButtonStyling => include_str!("button_styling.rs"),
+        Page::SimpleLayout => include_str!("simple_layout.rs"),
+        Page::LayoutComposition => include_str!("layout_composition.rs"),
+        Page::LayoutPlayground => include_str!("layout_playground.rs"),
+        Page::LayoutGallery => include_str!("layout_gallery.rs"),
+        Page::Theming => include_str!("theming.rs"),
+        Page::ResponsiveStyling => include_str!("responsive_styling.rs"),
+        Page::LoadTest => include_str!("thousandtest.rs"),
+        Page::GettingStarted => include_str!("getting_started.rs"),
+        Page::ExtendingSeed => include_str!("extending_seed.rs"),
+        Page::HooksHome => include_str!("hooks_home.rs"),
+        Page::HooksGettingStarted => include_str!("hooks_getting_started.rs"),
+        Page::HooksApi => include_str!("hooks_api.rs"),
+        Page::HooksTutorial => include_str!("hooks_tutorial.rs"),
+    }
+}
+
+#[topo::nested]
+pub fn view() -> Node<Msg> {
+    let query = use_state(String::new);
+    let text = query.get();
+
+    let results = if text.trim().is_empty() {
+        None
+    } else {
+        Some(INDEX.with(|index| {
+            index
+                .search(&text, MAX_RESULTS)
+                .iter()
+                .map(|result| result_view(result, query))
+                .collect::<Vec<_>>()
+        }))
+    };
+
+    div![
+        class!["search"],
+        attrs! {At::from("role") => "search"},
+        input![
+            attrs! {
+                At::Id => SEARCH_INPUT_ID,
+                At::Type => "search",
+                At::Placeholder => "Search docs",
+                At::AutoComplete => "off",
+                At::from("aria-label") => "Search documentation",
+                At::Value => text,
+            },
+            query.input_ev(Ev::Input, |query, text| *query = text),
+            keyboard_ev(Ev::KeyDown, move |event| {
+                if event.key() == "Escape" {
+                    query.set(String::new());
+                    Some(Msg::NoOp)
+                } else {
+                    None
+                }
+            }),
+        ],
+        match results {
+            Some(results) if results.is_empty() => {
+                div![class!["search-results", "search-empty"], "No results"]
+            }
+            Some(results) => ul![class!["search-results"], results],
+            None => empty![],
+        }
+    ]
+}
+
+fn result_view(result: &SearchResult, query: StateAccess<String>) -> Node<Msg> {
+    let document = result.document;
+    let snippet = &result.snippet;
+
+    li![a![
+        attrs! {At::Href => document.link()},
+        div![
+            class!["search-result-title"],
+            document.page_title.as_str(),
+            document
+                .heading
+                .as_ref()
+                .map(|heading| span![format!(" › {}", heading)]),
+        ],
+        div![
+            class!["search-result-snippet"],
+            snippet.before.as_str(),
+            if snippet.matched.is_empty() {
+                empty![]
+            } else {
+                mark![snippet.matched.as_str()]
+            },
+            snippet.after.as_str(),
+        ],
+        // following a result closes the list
+        query.on_click(|query| query.clear())
+    ]]
+}
+
+#[reaction]
+pub fn search_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(".search", s().position_relative().w(pc(100)))
+            .style(
+                ".search input",
+                s().w(pc(100))
+                    .px(px(12))
+                    .py(px(6))
+                    .radius(px(4))
+                    .b_width(px(1))
+                    .b_style_solid()
+                    .b_color(Color::MutedSecondary)
+                    .bg_color(Color::Background)
+                    .color(Color::MainText)
+                    .font_size(FontSize::Body),
+            )
+            .style(".search input", s().focus().b_color(Color::Primary))
+            .style(
+                ".search-results",
+                s().position_absolute()
+                    .top(pc(100))
+                    .right(px(0))
+                    .z_index("3")
+                    .w("min(480px, 90vw)")
+                    .max_height("70vh")
+                    .overflow_y_auto()
+                    .mt(px(4))
+                    .p(px(4))
+                    .radius(px(4))
+                    .bg_color(Color::Background)
+                    .box_shadow(Shadow::RightEdge),
+            )
+            .style(".search-empty", s().p(px(12)).color(Color::MutedText))
+            .style(
+                ".search-results a",
+                s().display_block()
+                    .p(px(8))
+                    .radius(px(4))
+                    .text_decoration_none(),
+            )
+            .style(
+                ".search-results a",
+                s().hover().bg_color(Color::MutedPrimary),
+            )
+            .style(
+                ".search-results a",
+                s().focus().bg_color(Color::MutedPrimary),
+            )
+            .style(
+                ".search-result-title",
+                s().font_weight_v700().color(Color::MainText),
+            )
+            .style(".search-result-title span", s().color(Color::Primary))
+            .style(
+                ".search-result-snippet",
+                s().font_size(FontSize::Caption)
+                    .line_height(LineHeight::Caption)
+                    .color(Color::MutedText),
+            )
+            .style(
+                ".search-result-snippet mark",
+                s().bg_color(Color::Highlight).color(Color::MainText),
+            )
+            .activate_styles()
+    });
+}
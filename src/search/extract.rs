@@ -0,0 +1,308 @@
+use super::index::Document;
+use crate::anchors::Slugger;
+
+// Extracting page text
+// --------------------
+//
+// Pages are view functions, so their text is taken from their source instead: every `prose!` string
+// and the `title = "..."` of the api sections that render as prose h3s. These are the headings that
+// `add_heading_anchors` anchors, headings of components such as `h2!["Preview"]` are left out. String
+// literals and comments are skipped while scanning so that code examples quoted inside strings are
+// not mistaken for page content.
+
+enum Part {
+    Heading(u8, String),
+    Markdown(String),
+}
+
+// The documents of one page, one per section.
+pub fn page_documents(page_title: &str, href: &str, source: &str) -> Vec<Document> {
+    let mut documents = vec![];
+    let mut slugger = Slugger::new();
+    let mut heading: Option<(String, String)> = None;
+    let mut body = String::new();
+
+    let mut finish_section = |heading: Option<(String, String)>, body: &mut String| {
+        let text = body.split_whitespace().collect::<Vec<_>>().join(" ");
+        body.clear();
+        // the introduction is kept even when empty, it is how the page title is found
+        documents.push(Document {
+            page_title: page_title.to_string(),
+            href: href.to_string(),
+            anchor: heading.as_ref().map(|(_, anchor)| anchor.clone()),
+            heading: heading.map(|(text, _)| text),
+            body: text,
+        });
+    };
+
+    for (level, line) in lines(source) {
+        match level {
+            Some(level) if level == 2 || level == 3 => {
+                finish_section(heading.take(), &mut body);
+                let anchor = slugger.slug(&line);
+                heading = Some((line, anchor));
+            }
+            // the h1 repeats the page title
+            Some(_) => {}
+            None => {
+                body.push_str(&line);
+                body.push(' ');
+            }
+        }
+    }
+    finish_section(heading.take(), &mut body);
+
+    documents
+}
+
+// Headings (with their level) and plain text lines of the page in source order.
+fn lines(source: &str) -> Vec<(Option<u8>, String)> {
+    let mut lines = vec![];
+    for part in parts(source) {
+        match part {
+            Part::Heading(level, text) => lines.push((Some(level), text)),
+            Part::Markdown(markdown) => {
+                let mut in_fence = false;
+                for line in markdown.lines() {
+                    let trimmed = line.trim();
+                    if trimmed.starts_with("```") {
+                        in_fence = !in_fence;
+                    } else if !in_fence && trimmed.starts_with('#') {
+                        let level = trimmed.chars().take_while(|c| *c == '#').count();
+                        let text = trimmed[level..].trim();
+                        if level <= 6 && !text.is_empty() {
+                            lines.push((Some(level as u8), plain_text(text)));
+                        }
+                    } else {
+                        lines.push((None, plain_text(trimmed)));
+                    }
+                }
+            }
+        }
+    }
+    lines
+}
+
+// Strips the markdown syntax that would otherwise show up in snippets.
+fn plain_text(markdown: &str) -> String {
+    let mut text = String::new();
+    let mut chars = markdown.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '`' | '*' | '[' => {}
+            // `[text](url)` keeps the text
+            ']' if chars.peek() == Some(&'(') => {
+                while let Some(c) = chars.next() {
+                    if c == ')' {
+                        break;
+                    }
+                }
+            }
+            ']' => {}
+            '!' if chars.peek() == Some(&'[') => {}
+            c => text.push(c),
+        }
+    }
+    text
+}
+
+fn parts(source: &str) -> Vec<Part> {
+    let mut parts = vec![];
+    let mut scanner = Scanner { source, pos: 0 };
+
+    while scanner.pos < source.len() {
+        let rest = scanner.rest();
+        if rest.starts_with("//") {
+            scanner.skip_line();
+        } else if rest.starts_with("prose![") {
+            scanner.pos += "prose![".len();
+            scanner.skip_whitespace();
+            if let Some(markdown) = scanner.string_literal() {
+                parts.push(Part::Markdown(markdown));
+            }
+        } else if rest.starts_with("title = \"") {
+            scanner.pos += "title = ".len();
+            if let Some(text) = scanner.string_literal() {
+                parts.push(Part::Heading(3, text));
+            }
+        } else if scanner.string_literal().is_none() && !scanner.skip_char_literal() {
+            scanner.advance();
+        }
+    }
+    parts
+}
+
+struct Scanner<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn advance(&mut self) {
+        if let Some(c) = self.rest().chars().next() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_line(&mut self) {
+        match self.rest().find('\n') {
+            Some(end) => self.pos += end + 1,
+            None => self.pos = self.source.len(),
+        }
+    }
+
+    // Skips a char literal such as `'"'`, which would otherwise start a string.
+    fn skip_char_literal(&mut self) -> bool {
+        let rest = self.rest().as_bytes();
+        let len = match rest {
+            [b'\'', b'\\', _, b'\'', ..] => 4,
+            [b'\'', c, b'\'', ..] if *c != b'\\' => 3,
+            _ => return false,
+        };
+        self.pos += len;
+        true
+    }
+
+    // Reads a `"..."` or `r#"..."#` literal at the current position, `None` if there isn't one.
+    // Escapes in normal strings are kept as written apart from `\"` and `\n`.
+    fn string_literal(&mut self) -> Option<String> {
+        let rest = self.rest();
+        if rest.starts_with('"') {
+            let mut escaped = false;
+            for (index, c) in rest.char_indices().skip(1) {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => {
+                        self.pos += index + 1;
+                        return Some(rest[1..index].replace("\\\"", "\"").replace("\\n", "\n"));
+                    }
+                    _ => {}
+                }
+            }
+            self.pos = self.source.len();
+            None
+        } else if rest.starts_with("r\"") || rest.starts_with("r#") {
+            let hashes = rest[1..].chars().take_while(|c| *c == '#').count();
+            if rest[1 + hashes..].chars().next() != Some('"') {
+                return None;
+            }
+            let start = 1 + hashes + 1;
+            let terminator = format!("\"{}", "#".repeat(hashes));
+            match rest[start..].find(&terminator) {
+                Some(end) => {
+                    self.pos += start + end + terminator.len();
+                    Some(rest[start..start + end].to_string())
+                }
+                None => {
+                    self.pos = self.source.len();
+                    None
+                }
+            }
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_markdown_into_sections() {
+        let source = r####"
+            prose![r#"
+Intro text.
+
+## First Section
+
+Some `code` text.
+
+### Sub Section
+
+More text.
+"#]
+        "####;
+        let documents = page_documents("Page", "/page", source);
+        let sections = documents
+            .iter()
+            .map(|d| (d.heading.clone(), d.anchor.clone(), d.body.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            vec![
+                (None, None, "Intro text.".to_string()),
+                (
+                    Some("First Section".to_string()),
+                    Some("first-section".to_string()),
+                    "Some code text.".to_string()
+                ),
+                (
+                    Some("Sub Section".to_string()),
+                    Some("sub-section".to_string()),
+                    "More text.".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_prose_headings_and_api_titles() {
+        let source = r###"
+            prose!["# Page"],
+            prose!["## Unstyled Button"],
+            sec![title = "use_state".to_string()],
+            h2!["Preview"],
+            md![&source],
+        "###;
+        let headings = page_documents("Page", "/page", source)
+            .into_iter()
+            .filter_map(|d| d.heading)
+            .collect::<Vec<_>>();
+        assert_eq!(headings, vec!["Unstyled Button", "use_state"]);
+    }
+
+    #[test]
+    fn ignores_code_fences_and_quoted_code() {
+        let source = r####"
+            prose![r#"
+## Real
+
+```toml
+# not a heading
+```
+"#]
+            code_example![r#"prose!["## Not a heading either"]"#]
+        "####;
+        let headings = page_documents("Page", "/page", source)
+            .into_iter()
+            .filter_map(|d| d.heading)
+            .collect::<Vec<_>>();
+        assert_eq!(headings, vec!["Real"]);
+    }
+
+    #[test]
+    fn duplicate_headings_get_the_same_anchors_as_the_page() {
+        let source = r###"prose!["## Example\n\n## Example"]"###;
+        let anchors = page_documents("Page", "/page", source)
+            .into_iter()
+            .filter_map(|d| d.anchor)
+            .collect::<Vec<_>>();
+        assert_eq!(anchors, vec!["example", "example-1"]);
+    }
+
+    #[test]
+    fn links_keep_their_text() {
+        assert_eq!(plain_text("see [the docs](/docs) now"), "see the docs now");
+    }
+}
@@ -0,0 +1,322 @@
+// A searchable section of a page, either the introduction before the first heading or
+// everything under one h2/h3 heading.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Document {
+    pub page_title: String,
+    pub href: String,
+    pub heading: Option<String>,
+    // Id of the heading anchor, see `anchors.rs`.
+    pub anchor: Option<String>,
+    pub body: String,
+}
+
+impl Document {
+    // Link to the page, or straight to the heading for sections.
+    pub fn link(&self) -> String {
+        match &self.anchor {
+            Some(anchor) => format!("{}#{}", self.href, anchor),
+            None => self.href.clone(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Snippet {
+    pub before: String,
+    pub matched: String,
+    pub after: String,
+}
+
+pub struct SearchResult<'a> {
+    pub document: &'a Document,
+    pub score: u32,
+    pub snippet: Snippet,
+}
+
+// Weights of a match in each part of a document. A page title only counts for the page's
+// introduction, otherwise every section of the page would match.
+const PAGE_TITLE_WEIGHT: u32 = 8;
+const HEADING_WEIGHT: u32 = 5;
+const BODY_WEIGHT: u32 = 1;
+// Repeating a term in the body stops adding to the score after this many times.
+const MAX_BODY_MATCHES: u32 = 5;
+
+// Characters of context either side of the matched term in a snippet.
+const SNIPPET_CONTEXT: usize = 60;
+
+pub struct SearchIndex {
+    documents: Vec<Document>,
+}
+
+impl SearchIndex {
+    pub fn new(documents: Vec<Document>) -> SearchIndex {
+        SearchIndex { documents }
+    }
+
+    pub fn documents(&self) -> &[Document] {
+        &self.documents
+    }
+
+    // The best `limit` documents containing every term of `query`, best first.
+    // Matching ignores ascii case, documents with equal scores keep their index order.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult<'_>> {
+        let terms = terms(query);
+        if terms.is_empty() {
+            return vec![];
+        }
+
+        let mut results = self
+            .documents
+            .iter()
+            .filter_map(|document| {
+                score(document, &terms).map(|score| SearchResult {
+                    document,
+                    score,
+                    snippet: snippet(&document.body, &terms),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // `sort_by` is stable so ties stay in index order
+        results.sort_by(|a, b| b.score.cmp(&a.score));
+        results.truncate(limit);
+        results
+    }
+}
+
+fn terms(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .map(|term| term.to_ascii_lowercase())
+        .collect()
+}
+
+// `None` unless every term is found somewhere in the document.
+fn score(document: &Document, terms: &[String]) -> Option<u32> {
+    let page_title = if document.heading.is_none() {
+        document.page_title.to_ascii_lowercase()
+    } else {
+        String::new()
+    };
+    let heading = document
+        .heading
+        .as_ref()
+        .map(|heading| heading.to_ascii_lowercase())
+        .unwrap_or_default();
+    let body = document.body.to_ascii_lowercase();
+
+    terms.iter().try_fold(0, |total, term| {
+        let in_title = page_title.contains(term.as_str());
+        let in_heading = heading.contains(term.as_str());
+        let body_matches = (body.matches(term.as_str()).count() as u32).min(MAX_BODY_MATCHES);
+
+        if !in_title && !in_heading && body_matches == 0 {
+            return None;
+        }
+        Some(
+            total
+                + if in_title { PAGE_TITLE_WEIGHT } else { 0 }
+                + if in_heading { HEADING_WEIGHT } else { 0 }
+                + body_matches * BODY_WEIGHT,
+        )
+    })
+}
+
+// The body around the first match of any term, or the start of the body when only the title
+// or heading matched.
+fn snippet(body: &str, terms: &[String]) -> Snippet {
+    let lower = body.to_ascii_lowercase();
+    let found = terms
+        .iter()
+        .filter_map(|term| lower.find(term.as_str()).map(|start| (start, term.len())))
+        .min();
+
+    match found {
+        Some((start, len)) => {
+            let end = start + len;
+            let from = floor_char_boundary(body, start.saturating_sub(SNIPPET_CONTEXT));
+            let to = ceil_char_boundary(body, (end + SNIPPET_CONTEXT).min(body.len()));
+            Snippet {
+                before: format!(
+                    "{}{}",
+                    if from > 0 { "…" } else { "" },
+                    &body[from..start]
+                ),
+                matched: body[start..end].to_string(),
+                after: format!(
+                    "{}{}",
+                    &body[end..to],
+                    if to < body.len() { "…" } else { "" }
+                ),
+            }
+        }
+        None => {
+            let to = ceil_char_boundary(body, (SNIPPET_CONTEXT * 2).min(body.len()));
+            Snippet {
+                before: String::new(),
+                matched: String::new(),
+                after: format!(
+                    "{}{}",
+                    &body[..to],
+                    if to < body.len() { "…" } else { "" }
+                ),
+            }
+        }
+    }
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn ceil_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(page_title: &str, heading: Option<&str>, body: &str) -> Document {
+        Document {
+            page_title: page_title.to_string(),
+            href: "/page".to_string(),
+            heading: heading.map(str::to_string),
+            anchor: heading.map(|heading| heading.to_lowercase()),
+            body: body.to_string(),
+        }
+    }
+
+    fn ranked(index: &SearchIndex, query: &str) -> Vec<String> {
+        index
+            .search(query, 10)
+            .iter()
+            .map(|result| {
+                result
+                    .document
+                    .heading
+                    .clone()
+                    .unwrap_or_else(|| result.document.page_title.clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty_query_finds_nothing() {
+        let index = SearchIndex::new(vec![document("Theming", None, "themes")]);
+        assert!(index.search("", 10).is_empty());
+        assert!(index.search("   ", 10).is_empty());
+    }
+
+    #[test]
+    fn title_outranks_heading_outranks_body() {
+        let index = SearchIndex::new(vec![
+            document("Layout", Some("Body"), "a layout in the body"),
+            document("Layout", Some("Layout Areas"), "areas"),
+            document("Layout", None, "introduction"),
+        ]);
+        assert_eq!(ranked(&index, "layout"), vec!["Layout", "Layout Areas", "Body"]);
+    }
+
+    #[test]
+    fn page_title_only_counts_for_the_introduction() {
+        let index = SearchIndex::new(vec![document("Theming", Some("Colors"), "colors")]);
+        assert!(index.search("theming", 10).is_empty());
+    }
+
+    #[test]
+    fn more_body_matches_rank_higher() {
+        let index = SearchIndex::new(vec![
+            document("Hooks", Some("Once"), "state"),
+            document("Hooks", Some("Twice"), "state and more state"),
+        ]);
+        assert_eq!(ranked(&index, "state"), vec!["Twice", "Once"]);
+    }
+
+    #[test]
+    fn body_matches_are_capped() {
+        let index = SearchIndex::new(vec![
+            document("Hooks", Some("Spam"), &"state ".repeat(50)),
+            document("Hooks", Some("State"), "state"),
+        ]);
+        assert_eq!(ranked(&index, "state"), vec!["State", "Spam"]);
+    }
+
+    #[test]
+    fn every_term_must_match() {
+        let index = SearchIndex::new(vec![
+            document("Hooks", Some("Use State"), "use_state stores state"),
+            document("Hooks", Some("Atoms"), "global state"),
+        ]);
+        assert_eq!(ranked(&index, "state use_state"), vec!["Use State"]);
+        assert!(index.search("state missing", 10).is_empty());
+    }
+
+    #[test]
+    fn matching_ignores_case() {
+        let index = SearchIndex::new(vec![document("Hooks", Some("StateAccess"), "")]);
+        assert_eq!(ranked(&index, "STATEACCESS"), vec!["StateAccess"]);
+    }
+
+    #[test]
+    fn ties_keep_index_order() {
+        let index = SearchIndex::new(vec![
+            document("Hooks", Some("First"), "seed"),
+            document("Hooks", Some("Second"), "seed"),
+        ]);
+        assert_eq!(ranked(&index, "seed"), vec!["First", "Second"]);
+    }
+
+    #[test]
+    fn results_are_limited() {
+        let index = SearchIndex::new(
+            (0..20)
+                .map(|n| document("Page", Some(&n.to_string()), "seed"))
+                .collect(),
+        );
+        assert_eq!(index.search("seed", 8).len(), 8);
+    }
+
+    #[test]
+    fn snippet_surrounds_the_match() {
+        let body = format!("{}needle{}", "a".repeat(100), "b".repeat(100));
+        let snippet = snippet(&body, &["needle".to_string()]);
+        assert_eq!(snippet.matched, "needle");
+        assert_eq!(snippet.before, format!("…{}", "a".repeat(SNIPPET_CONTEXT)));
+        assert_eq!(snippet.after, format!("{}…", "b".repeat(SNIPPET_CONTEXT)));
+    }
+
+    #[test]
+    fn snippet_keeps_the_case_of_the_text() {
+        let snippet = snippet("Use StateAccess here", &["stateaccess".to_string()]);
+        assert_eq!(
+            snippet,
+            Snippet {
+                before: "Use ".to_string(),
+                matched: "StateAccess".to_string(),
+                after: " here".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn snippet_respects_char_boundaries() {
+        let body = format!("{}needle{}", "é".repeat(40), "ü".repeat(40));
+        let snippet = snippet(&body, &["needle".to_string()]);
+        assert_eq!(snippet.matched, "needle");
+        assert!(snippet.before.ends_with('é'));
+        assert!(snippet.after.starts_with('ü'));
+    }
+
+    #[test]
+    fn links_go_to_the_heading_anchor() {
+        assert_eq!(document("Hooks", Some("atoms"), "").link(), "/page#atoms");
+        assert_eq!(document("Hooks", None, "").link(), "/page");
+    }
+}
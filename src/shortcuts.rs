@@ -0,0 +1,220 @@
+use crate::app_styling::theme::*;
+use crate::Msg;
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::px;
+use seed_style::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// Keyboard shortcuts
+// ------------------
+//
+// A registry of global key bindings handled from the window keydown stream. Like commands, any module
+// can add bindings with `register`, usually from a `register_shortcuts()` function called in `init`.
+//
+// Keys are written as `event.key()` values, optionally prefixed with `Ctrl+`, which also matches Cmd
+// on macs. Plain keys are ignored while typing in inputs and textareas, bindings that should still
+// work there are marked with `in_inputs()`.
+//
+// `?` shows every registered binding.
+
+#[derive(Clone)]
+pub struct Shortcut {
+    pub keys: Vec<&'static str>,
+    pub description: &'static str,
+    in_inputs: bool,
+    action: Rc<dyn Fn() -> Msg>,
+}
+
+impl Shortcut {
+    pub fn new(
+        keys: &[&'static str],
+        description: &'static str,
+        action: impl Fn() -> Msg + 'static,
+    ) -> Shortcut {
+        Shortcut {
+            keys: keys.to_vec(),
+            description,
+            in_inputs: false,
+            action: Rc::new(action),
+        }
+    }
+
+    // Also handle the binding while an input has focus.
+    pub fn in_inputs(mut self) -> Shortcut {
+        self.in_inputs = true;
+        self
+    }
+
+    fn matches(&self, event: &web_sys::KeyboardEvent) -> bool {
+        self.keys.iter().any(|keys| key_matches(keys, event))
+    }
+}
+
+fn key_matches(keys: &str, event: &web_sys::KeyboardEvent) -> bool {
+    let (ctrl, key) = if keys.starts_with("Ctrl+") {
+        (true, &keys["Ctrl+".len()..])
+    } else {
+        (false, keys)
+    };
+    ctrl == (event.ctrl_key() || event.meta_key())
+        && !event.alt_key()
+        && event.key().eq_ignore_ascii_case(key)
+}
+
+thread_local! {
+    static SHORTCUTS: RefCell<Vec<Shortcut>> = RefCell::new(vec![]);
+}
+
+pub fn register(shortcut: Shortcut) {
+    SHORTCUTS.with(|shortcuts| shortcuts.borrow_mut().push(shortcut));
+}
+
+fn registered() -> Vec<Shortcut> {
+    SHORTCUTS.with(|shortcuts| shortcuts.borrow().clone())
+}
+
+pub fn register_shortcuts() {
+    register(Shortcut::new(&["?"], "Show keyboard shortcuts", || {
+        help_open().update(|open| *open = !*open);
+        Msg::NoOp
+    }));
+}
+
+#[atom]
+fn help_open() -> bool {
+    false
+}
+
+pub fn on_window_key_down(event: &web_sys::KeyboardEvent) -> Option<Msg> {
+    if help_open().get() && event.key() == "Escape" {
+        help_open().set(false);
+        return Some(Msg::NoOp);
+    }
+
+    let typing = is_typing(event);
+    let shortcut = registered()
+        .into_iter()
+        .find(|shortcut| (shortcut.in_inputs || !typing) && shortcut.matches(event))?;
+
+    // e.g. stops `/` being typed into the search box it focuses
+    event.prevent_default();
+    Some((shortcut.action)())
+}
+
+// Whether the key went to a text input, textarea, select or editable element.
+fn is_typing(event: &web_sys::KeyboardEvent) -> bool {
+    let target = match event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        Some(target) => target,
+        None => return false,
+    };
+    if target.is_content_editable() {
+        return true;
+    }
+    match target.tag_name().as_str() {
+        "TEXTAREA" | "SELECT" => true,
+        "INPUT" => !matches!(
+            target.get_attribute("type").as_deref(),
+            Some("button") | Some("checkbox") | Some("radio") | Some("submit")
+        ),
+        _ => false,
+    }
+}
+
+pub fn help_view() -> Node<Msg> {
+    if !help_open().get() {
+        return empty![];
+    }
+
+    div![
+        class!["shortcuts-backdrop"],
+        mouse_ev(Ev::Click, |_| {
+            help_open().set(false);
+            Msg::NoOp
+        }),
+        div![
+            class!["shortcuts-help"],
+            attrs! {
+                At::from("role") => "dialog",
+                At::from("aria-modal") => "true",
+                At::from("aria-label") => "Keyboard shortcuts",
+            },
+            ev(Ev::Click, |event| event.stop_propagation()),
+            h2!["Keyboard shortcuts"],
+            table![tbody![registered().iter().map(|shortcut| {
+                tr![
+                    td![shortcut
+                        .keys
+                        .iter()
+                        .enumerate()
+                        .map(|(index, keys)| {
+                            vec![
+                                if index > 0 { span![" or "] } else { empty![] },
+                                kbd![keys.replace("Ctrl+", "Ctrl + ")],
+                            ]
+                        })
+                        .flatten()
+                        .collect::<Vec<_>>()],
+                    td![shortcut.description],
+                ]
+            })]],
+            p![class!["shortcuts-note"], "Press Escape to close."]
+        ]
+    ]
+}
+
+#[reaction]
+pub fn shortcut_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(
+                ".shortcuts-backdrop",
+                s().position_fixed()
+                    .top(px(0))
+                    .right(px(0))
+                    .bottom(px(0))
+                    .left(px(0))
+                    .z_index("10")
+                    .display_flex()
+                    .justify_content_center()
+                    .align_items_center()
+                    .bg_color("rgba(0,0,0,0.4)"),
+            )
+            .style(
+                ".shortcuts-help",
+                s().w("min(480px, 92vw)")
+                    .max_height("80vh")
+                    .overflow_y_auto()
+                    .p(px(24))
+                    .radius(px(6))
+                    .bg_color(Color::Background)
+                    .color(Color::MainText)
+                    .box_shadow(Shadow::RightEdge),
+            )
+            .style(
+                ".shortcuts-help h2",
+                s().mt(px(0)).font_size(FontSize::H3).line_height(LineHeight::H3),
+            )
+            .style(".shortcuts-help td", s().py(px(6)).pr(px(16)))
+            .style(
+                ".shortcuts-help kbd",
+                s().px(px(6))
+                    .py(px(2))
+                    .radius(px(3))
+                    .b_width(px(1))
+                    .b_style_solid()
+                    .b_color(Color::MutedSecondary)
+                    .bg_color(Color::MutedSecondary)
+                    .font_family("monospace"),
+            )
+            .style(
+                ".shortcuts-note",
+                s().mb(px(0)).font_size(FontSize::Caption).color(Color::MutedText),
+            )
+            .activate_styles()
+    });
+}
@@ -13,8 +13,8 @@ use seed_style::Row;
 pub fn view(model: &Model) -> Node<Msg> {
     render_centred_article(model, |_| {
         div![
-            h1!["Simple Layout"],
-            md![r#"
+            prose!["# Simple Layout"],
+            prose![r#"
 Seed Style provides both simple layout primitives as well as a fully comprehensive compositing layout system. 
 
 ## Row Layout
@@ -73,7 +73,7 @@ Example with some colour styling:
             Item![s().h(pc(100)).bg_color(seed_colors::Green::No4), "center hello" , align = RowAlign::Center],
             
     ],
-    md![r#"
+    prose![r#"
 ## Column Layout
 
 You can layout elements within a vertical Column by using the `Column![]` macro. Each Item is then aligned top,center, or bottom by setting
@@ -133,7 +133,7 @@ Example with some colour styling:
         
 ],
 
-md![r#"of course you can put rows in columns:"#],
+prose![r#"of course you can put rows in columns:"#],
 
 Column![
         gap = px(12),
@@ -167,7 +167,7 @@ Column![
     ]],
       
 ],
-md![r#"and columns in rows:"#],
+prose![r#"and columns in rows:"#],
 Row![
     gap = px(12),
     Item![
@@ -201,7 +201,7 @@ Row![
         
 ]],
 ],
-        md![
+        prose![
 r#"## Creating your own layout primitives
 
 The `Row` and `Column` described here are created with tools from Seed Style. Look in the [extending seed](/extending_seed) section 
@@ -0,0 +1,160 @@
+use crate::{nav, Model, Msg};
+use seed::{prelude::*, *};
+use seed_hooks::*;
+
+// Swipe gestures for the nav drawer
+// ---------------------------------
+//
+// On phones a swipe right starting at the left edge of the screen opens the drawer and a swipe
+// left anywhere closes it.
+//
+// While dragging, the drawer follows the finger through an inline `transform` on the drawer
+// element, so touchmove events never re-render the app. On release the inline style is removed,
+// `show_drawer` is set and the drawer's own transition snaps it the rest of the way.
+
+// Width of the drawer in `nav.rs`.
+const DRAWER_WIDTH: f64 = 256.;
+// Only touches starting this close to the left edge open a closed drawer.
+const EDGE_WIDTH: f64 = 24.;
+// Movement before a touch is treated as either a horizontal drag or a vertical scroll.
+const SLOP: f64 = 10.;
+// A release faster than this (px per ms) opens or closes the drawer whatever the distance.
+const FLING_VELOCITY: f64 = 0.3;
+
+#[derive(Clone)]
+pub struct Drag {
+    start_x: f64,
+    start_y: f64,
+    from_open: bool,
+    // `None` until the touch has moved past `SLOP`.
+    horizontal: Option<bool>,
+    offset: f64,
+    last_x: f64,
+    last_time: f64,
+    velocity: f64,
+}
+
+impl Drag {
+    // How far the drawer is translated, from `-DRAWER_WIDTH` (closed) to 0 (open).
+    fn offset_at(&self, x: f64) -> f64 {
+        let dx = x - self.start_x;
+        let start = if self.from_open { 0. } else { -DRAWER_WIDTH };
+        (start + dx).max(-DRAWER_WIDTH).min(0.)
+    }
+
+    fn should_open(&self) -> bool {
+        if self.velocity > FLING_VELOCITY {
+            true
+        } else if self.velocity < -FLING_VELOCITY {
+            false
+        } else {
+            self.offset > -DRAWER_WIDTH / 2.
+        }
+    }
+}
+
+// Adds the swipe handlers to the root element of the app.
+pub fn add_swipe_handlers(root: &mut Node<Msg>, model: &Model, drag: StateAccess<Option<Drag>>) {
+    let root = match root {
+        Node::Element(root) => root,
+        _ => return,
+    };
+    let show_drawer = model.show_drawer;
+
+    root.add_event_handler(touch_ev(Ev::TouchStart, move |event| {
+        // the drawer only slides at the breakpoints where its toggle is shown
+        if get_html_element_by_id(nav::TOGGLE_ID).is_none() || event.touches().length() != 1 {
+            return;
+        }
+        if let Some(touch) = event.touches().item(0) {
+            let (x, y) = (f64::from(touch.client_x()), f64::from(touch.client_y()));
+            let from_open = show_drawer.get();
+            if from_open || x <= EDGE_WIDTH {
+                drag.set(Some(Drag {
+                    start_x: x,
+                    start_y: y,
+                    from_open,
+                    horizontal: None,
+                    offset: if from_open { 0. } else { -DRAWER_WIDTH },
+                    last_x: x,
+                    last_time: event.time_stamp(),
+                    velocity: 0.,
+                }));
+            }
+        }
+    }));
+
+    root.add_event_handler(touch_ev(Ev::TouchMove, move |event| {
+        let touch = match event.touches().item(0) {
+            Some(touch) => touch,
+            None => return,
+        };
+        let (x, y) = (f64::from(touch.client_x()), f64::from(touch.client_y()));
+        let time = event.time_stamp();
+
+        drag.update(|drag| {
+            let current = match drag {
+                Some(current) => current,
+                None => return,
+            };
+            if current.horizontal.is_none() {
+                let (dx, dy) = ((x - current.start_x).abs(), (y - current.start_y).abs());
+                if dx < SLOP && dy < SLOP {
+                    return;
+                }
+                current.horizontal = Some(dx > dy);
+            }
+            if current.horizontal != Some(true) {
+                // a vertical scroll, leave it to the browser
+                *drag = None;
+                return;
+            }
+
+            event.prevent_default();
+            if time > current.last_time {
+                current.velocity = (x - current.last_x) / (time - current.last_time);
+            }
+            current.last_x = x;
+            current.last_time = time;
+            current.offset = current.offset_at(x);
+            follow_finger(current.offset);
+        });
+    }));
+
+    root.add_event_handler(touch_ev(Ev::TouchEnd, move |_| release(drag, show_drawer)));
+    root.add_event_handler(touch_ev(Ev::TouchCancel, move |_| {
+        drag.set(None);
+        clear_drag_style();
+        Some(Msg::NoOp)
+    }));
+}
+
+fn release(drag: StateAccess<Option<Drag>>, show_drawer: StateAccess<bool>) -> Option<Msg> {
+    let released = drag.get()?;
+    drag.set(None);
+    if released.horizontal != Some(true) {
+        return None;
+    }
+
+    clear_drag_style();
+    show_drawer.set(released.should_open());
+    Some(Msg::NoOp)
+}
+
+fn follow_finger(offset: f64) {
+    if let Some(drawer) = get_html_element_by_id(nav::DRAWER_ID) {
+        let _ = drawer.set_attribute(
+            "style",
+            &format!(
+                "transform: translateX({}px); transition: none; visibility: visible;",
+                offset
+            ),
+        );
+    }
+}
+
+fn clear_drag_style() {
+    if let Some(drawer) = get_html_element_by_id(nav::DRAWER_ID) {
+        let _ = drawer.remove_attribute("style");
+    }
+}
@@ -7,7 +7,7 @@ use seed_style::*;
 use seed_style::{pc, px};
 
 pub fn view(_model: &Model) -> Node<Msg> {
-    div![md![r#"
+    div![prose![r#"
 # Theming Support
 
 Seed Style supports the [Theme Specification](https://theme-ui.com/theme-spec/) which essentially a common format for a Theme Object which
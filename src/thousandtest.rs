@@ -1,3 +1,4 @@
+use crate::commands::{self, Command, CommandGroup};
 use crate::compositions::*;
 use crate::{Model, Msg};
 use rand::prelude::*;
@@ -6,13 +7,25 @@ use seed_hooks::*;
 use seed_style::px;
 use seed_style::*;
 
+// Set to render the styles, so the test can also be started from the command palette.
+#[atom]
+fn load_test_requested() -> bool {
+    false
+}
+
+pub fn register_commands() {
+    commands::register(Command::new(CommandGroup::Action, "Run load test", || {
+        load_test_requested().set(true);
+        Msg::GoTo("/load_test".to_string())
+    }));
+}
+
 #[topo::nested]
 pub fn view(model: &Model) -> Node<Msg> {
     render_centred_article(model, load_test)
 }
 
 fn load_test(_model: &Model) -> Node<Msg> {
-    let do_load_test = use_state(|| false);
     div![
         s().padding_x(3)
             .padding_y(2)
@@ -40,10 +53,13 @@ fn load_test(_model: &Model) -> Node<Msg> {
                 .px(px(24))
                 .py(px(8)),
             "Render 1,000 styles!",
-            do_load_test.on_click(|d| *d = true)
+            mouse_ev(Ev::Click, |_| {
+                load_test_requested().set(true);
+                Msg::NoOp
+            })
         ],
-        if do_load_test.get() {
-            do_load_test.set(false);
+        if load_test_requested().get() {
+            load_test_requested().set(false);
             div![
                 s().display_grid()
                     .grid_template_columns("repeat(40,auto)")
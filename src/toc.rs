@@ -0,0 +1,310 @@
+use crate::anchors::Heading;
+use crate::app_styling::theme::*;
+use crate::{header, Msg};
+use seed::{prelude::*, *};
+use seed_hooks::*;
+use seed_style::px;
+use seed_style::*;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+// Table of contents
+// -----------------
+//
+// An "On this page" list of an article's h2 and h3 headings. From `Breakpoint::Small` upwards it is
+// a sticky panel to the right of the article, at `Breakpoint::ExtraSmall` it collapses into a dropdown
+// above the article.
+//
+// The scrollspy uses an IntersectionObserver on the article headings and marks the link of the
+// current section with `data-active`. Like the code block toolbar this only touches the dom, scrolling
+// never causes a re-render.
+
+// Class given to an article whose headings are spied on.
+pub const ARTICLE_CLASS: &str = "toc-article";
+
+pub fn aside(headings: &[Heading]) -> Node<Msg> {
+    if headings.is_empty() {
+        return empty![];
+    }
+    spy_on_headings(headings);
+
+    nav![
+        class!["toc"],
+        attrs! {At::from("aria-label") => "On this page"},
+        s().only_and_below(Breakpoint::ExtraSmall).display_none(),
+        s().position_sticky()
+            .top(px(16))
+            .align_self_flex_start()
+            .max_height("calc(100vh - 32px)")
+            .overflow_y_auto(),
+        div![class!["toc-title"], "On this page"],
+        links(headings, false),
+    ]
+}
+
+pub fn dropdown(headings: &[Heading]) -> Node<Msg> {
+    if headings.is_empty() {
+        return empty![];
+    }
+
+    details![
+        class!["toc", "toc-dropdown"],
+        s().only_and_above(Breakpoint::Small).display_none(),
+        summary![class!["toc-title"], "On this page"],
+        links(headings, true),
+    ]
+}
+
+fn links(headings: &[Heading], closes_dropdown: bool) -> Node<Msg> {
+    ul![headings.iter().map(|heading| {
+        li![
+            class!["toc-h3" => heading.level == 3],
+            a![
+                attrs! {At::Href => format!("#{}", heading.id)},
+                heading.text.as_str(),
+                if closes_dropdown {
+                    Some(ev(Ev::Click, |event| close_dropdown(&event)))
+                } else {
+                    None
+                },
+            ]
+        ]
+    })]
+}
+
+fn close_dropdown(event: &web_sys::Event) {
+    let details = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .and_then(|link| link.closest("details").ok().flatten());
+    if let Some(details) = details {
+        let _ = details.remove_attribute("open");
+    }
+}
+
+// Scrollspy
+// ---------
+
+// The observer of the headings on the current page.
+struct Spy {
+    heading_ids: Vec<String>,
+    observer: IntersectionObserver,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+thread_local! {
+    static SPY: RefCell<Option<Spy>> = RefCell::new(None);
+    // The ids of the headings in the band, the observer only reports the headings that moved.
+    static IN_BAND: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+// The top of the band, below the header.
+const BAND_TOP: i32 = header::HEADER_HEIGHT + 16;
+
+// Sets up the observer once per page, re-renders of the same headings keep the observer.
+fn spy_on_headings(headings: &[Heading]) {
+    let heading_ids = headings
+        .iter()
+        .map(|heading| heading.id.clone())
+        .collect::<Vec<_>>();
+    let spying = SPY.with(|spy| {
+        spy.borrow()
+            .as_ref()
+            .map_or(false, |spy| spy.heading_ids == heading_ids)
+    });
+    if spying {
+        return;
+    }
+
+    after_render(move |_| {
+        let ids = heading_ids.clone();
+        let callback = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+            let current = IN_BAND.with(|in_band| {
+                let mut in_band = in_band.borrow_mut();
+                for entry in entries
+                    .iter()
+                    .filter_map(|entry| entry.dyn_into::<IntersectionObserverEntry>().ok())
+                {
+                    if entry.is_intersecting() {
+                        in_band.insert(entry.target().id());
+                    } else {
+                        in_band.remove(&entry.target().id());
+                    }
+                }
+                current_section(&ids, &in_band, is_above_band).map(str::to_string)
+            });
+            if let Some(id) = current {
+                set_active(&id);
+            }
+        }) as Box<dyn FnMut(js_sys::Array)>);
+
+        // A heading is current while it is in the top third of the window, below the header.
+        let mut options = IntersectionObserverInit::new();
+        options.root_margin(&format!("-{}px 0px -66% 0px", BAND_TOP));
+
+        let observer = match IntersectionObserver::new_with_options(
+            callback.as_ref().unchecked_ref(),
+            &options,
+        ) {
+            Ok(observer) => observer,
+            Err(_) => return,
+        };
+
+        let selector = format!(".{} .anchored", ARTICLE_CLASS);
+        if let Ok(headings) = document().query_selector_all(&selector) {
+            for index in 0..headings.length() {
+                if let Some(heading) = headings
+                    .item(index)
+                    .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+                {
+                    observer.observe(&heading);
+                }
+            }
+        }
+
+        stop_spying();
+        SPY.with(|spy| {
+            spy.replace(Some(Spy {
+                heading_ids: heading_ids.clone(),
+                observer,
+                _callback: callback,
+            }))
+        });
+    });
+}
+
+// Disconnects the observer, called when the page changes.
+pub fn stop_spying() {
+    SPY.with(|spy| {
+        if let Some(spy) = spy.borrow_mut().take() {
+            spy.observer.disconnect();
+        }
+    });
+    IN_BAND.with(|in_band| in_band.borrow_mut().clear());
+}
+
+// The id of the current section: the first heading in the band or, while the band is between two
+// headings, the last heading above it. `None` above the first heading.
+fn current_section<'a>(
+    heading_ids: &'a [String],
+    in_band: &HashSet<String>,
+    is_above_band: impl Fn(&str) -> bool,
+) -> Option<&'a str> {
+    heading_ids
+        .iter()
+        .find(|id| in_band.contains(*id))
+        .or_else(|| heading_ids.iter().take_while(|id| is_above_band(id.as_str())).last())
+        .map(String::as_str)
+}
+
+fn is_above_band(id: &str) -> bool {
+    document()
+        .get_element_by_id(id)
+        .map_or(false, |heading| {
+            heading.get_bounding_client_rect().top() < f64::from(BAND_TOP)
+        })
+}
+
+fn set_active(id: &str) {
+    let document = document();
+    if let Ok(links) = document.query_selector_all(".toc a[data-active]") {
+        for index in 0..links.length() {
+            if let Some(link) = links
+                .item(index)
+                .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+            {
+                let _ = link.remove_attribute("data-active");
+            }
+        }
+    }
+    if let Ok(links) = document.query_selector_all(&format!(".toc a[href='#{}']", id)) {
+        for index in 0..links.length() {
+            if let Some(link) = links
+                .item(index)
+                .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+            {
+                let _ = link.set_attribute("data-active", "");
+            }
+        }
+    }
+}
+
+#[reaction]
+pub fn toc_styles() -> () {
+    app_themes().on_update(|| {
+        GlobalStyle::new()
+            .style(".toc", s().font_size(FontSize::Caption).line_height(LineHeight::Caption))
+            .style(
+                ".toc-title",
+                s().font_weight_v700()
+                    .text_transform("uppercase")
+                    .letter_spacing(rem(0.05))
+                    .color(Color::MutedText)
+                    .mb(px(8)),
+            )
+            .style(".toc li", s().my(px(4)))
+            .style(".toc li.toc-h3", s().pl(px(12)))
+            .style(
+                ".toc a",
+                s().display_block()
+                    .pl(px(8))
+                    .color(Color::MutedText)
+                    .text_decoration_none()
+                    .border_left_width(px(2))
+                    .border_left_style("solid")
+                    .border_left_color("transparent"),
+            )
+            .style(".toc a", s().hover().color(Color::Primary))
+            .style(
+                ".toc a[data-active]",
+                s().color(Color::Primary).border_left_color(Color::Primary),
+            )
+            .style(
+                ".toc-dropdown",
+                s().mb(px(16))
+                    .p(px(8))
+                    .radius(px(4))
+                    .bg_color(Color::MutedSecondary),
+            )
+            .style(".toc-dropdown summary", s().cursor_pointer().mb(px(0)))
+            .style(".toc-dropdown[open] summary", s().mb(px(8)))
+            .style(".toc", s().media("@media print").display_none())
+            .activate_styles()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids() -> Vec<String> {
+        vec!["intro".to_string(), "usage".to_string(), "api".to_string()]
+    }
+
+    fn in_band(ids: &[&str]) -> HashSet<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn the_first_heading_in_the_band_is_current() {
+        let ids = ids();
+        assert_eq!(
+            current_section(&ids, &in_band(&["api", "usage"]), |_| true),
+            Some("usage")
+        );
+    }
+
+    #[test]
+    fn between_headings_the_last_one_above_the_band_is_current() {
+        let ids = ids();
+        let above = |id: &str| id == "intro" || id == "usage";
+        assert_eq!(current_section(&ids, &in_band(&[]), above), Some("usage"));
+    }
+
+    #[test]
+    fn above_the_first_heading_nothing_is_current() {
+        let ids = ids();
+        assert_eq!(current_section(&ids, &in_band(&[]), |_| false), None);
+    }
+}